@@ -0,0 +1,164 @@
+//! Compares the cost of the generated `Other` filter, which nests an `Or` inside a tuple,
+//! with a flattened alternative, `(Without<A>, <A as Disjoint>::Any)`, for a group of 16 "variants",
+//! and the cost of the generated `Only` filter, which is nested at this size, with a plain `With`.
+//!
+//! Filters are matched against archetypes when the query state is created or updated, not against each entity,
+//! so the shape of the filter only affects matching, and iterating costs the same for every equivalent filter.
+//!
+//! Measured over three runs, on one core of an Intel Xeon virtual machine, with Rust 1.95 and Bevy 0.14.2,
+//! for 257 archetypes and 51200 entities (µs per run, lowest to highest):
+//!
+//! | filter                | matching    | iterating   |
+//! |-----------------------|-------------|-------------|
+//! | generated `Other`     | 16.7 - 22.5 | 12.9 - 13.6 |
+//! | flat `(Without, Any)` | 17.8 - 20.1 | 13.3 - 14.2 |
+//! | hand-written `Other`  | 14.6 - 21.5 | 13.4 - 15.9 |
+//! | generated `Only`      | 1.7 - 2.8   | 0.4 - 0.7   |
+//! | `With<A>`             | 0.9 - 1.6   | 0.4 - 0.7   |
+//!
+//! The flattened `Other` is within the noise of the generated one, for matching and for iterating,
+//! so the generated filters are kept as they are. The nested `Only` takes about 1 µs longer to match than a plain `With`,
+//! once per query state, and iterates as fast.
+//!
+//! Run with `cargo bench --bench filters`.
+
+use std::{hint::black_box, time::Instant};
+
+use bevy::{
+    ecs::query::{QueryFilter, QueryState},
+    prelude::{Component, With, Without, World},
+};
+use bevy_djqf::{disjoint, Disjoint};
+
+macro_rules! components {
+    ($( $name:ident ),+) => {
+        $(
+            #[derive(Component, Default)]
+            struct $name;
+        )+
+    };
+}
+
+components!(A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P);
+
+disjoint!(A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P);
+
+// unrelated components, so that each "variant" is spread over many archetypes
+components!(X0, X1, X2, X3);
+
+#[derive(Component)]
+struct Value(u64);
+
+/// A flattened equivalent of `<A as Disjoint>::Other`.
+type FlatOther = (Without<A>, <A as Disjoint>::Any);
+
+/// The generated filter, written out by hand.
+type HandOther = (
+    Without<A>,
+    bevy::prelude::Or<(
+        With<B>,
+        With<C>,
+        With<D>,
+        With<E>,
+        With<F>,
+        With<G>,
+        With<H>,
+        With<I>,
+        With<J>,
+        With<K>,
+        With<L>,
+        With<M>,
+        With<N>,
+        With<O>,
+        With<P>,
+    )>,
+);
+
+const ENTITIES_PER_ARCHETYPE: u64 = 200;
+
+fn spawn<V: Component + Default>(world: &mut World) {
+    for extras in 0..16u8 {
+        for value in 0..ENTITIES_PER_ARCHETYPE {
+            let mut entity = world.spawn((V::default(), Value(value)));
+            if extras & 1 != 0 {
+                entity.insert(X0);
+            }
+            if extras & 2 != 0 {
+                entity.insert(X1);
+            }
+            if extras & 4 != 0 {
+                entity.insert(X2);
+            }
+            if extras & 8 != 0 {
+                entity.insert(X3);
+            }
+        }
+    }
+}
+
+fn world() -> World {
+    let mut world = World::new();
+    spawn::<A>(&mut world);
+    spawn::<B>(&mut world);
+    spawn::<C>(&mut world);
+    spawn::<D>(&mut world);
+    spawn::<E>(&mut world);
+    spawn::<F>(&mut world);
+    spawn::<G>(&mut world);
+    spawn::<H>(&mut world);
+    spawn::<I>(&mut world);
+    spawn::<J>(&mut world);
+    spawn::<K>(&mut world);
+    spawn::<L>(&mut world);
+    spawn::<M>(&mut world);
+    spawn::<N>(&mut world);
+    spawn::<O>(&mut world);
+    spawn::<P>(&mut world);
+    world
+}
+
+/// The average time of `iterations` runs of `f`, in microseconds.
+fn measure(iterations: u32, mut f: impl FnMut()) -> f64 {
+    // warm up
+    for _ in 0..iterations / 10 {
+        f();
+    }
+    let start = Instant::now();
+    for _ in 0..iterations {
+        f();
+    }
+    start.elapsed().as_secs_f64() * 1e6 / f64::from(iterations)
+}
+
+fn bench<F: QueryFilter>(name: &str, world: &mut World, variants: usize) {
+    // matching the filter against every archetype
+    let matching = measure(1_000, || {
+        black_box(QueryState::<&Value, F>::new(world));
+    });
+
+    // iterating the matched entities
+    let mut state = QueryState::<&Value, F>::new(world);
+    let expected = variants * 16 * ENTITIES_PER_ARCHETYPE as usize;
+    assert_eq!(state.iter(world).count(), expected);
+    let iterating = measure(1_000, || {
+        let sum: u64 = state.iter(world).map(|value| value.0).sum();
+        black_box(sum);
+    });
+
+    println!("{name:<24} matching: {matching:>8.2} µs   iterating: {iterating:>8.2} µs");
+}
+
+fn main() {
+    let mut world = world();
+    println!(
+        "{} archetypes, {} entities",
+        world.archetypes().len(),
+        world.entities().len()
+    );
+    bench::<<A as Disjoint>::Other>("generated Other", &mut world, 15);
+    bench::<FlatOther>("flat (Without, Any)", &mut world, 15);
+    bench::<HandOther>("hand-written Other", &mut world, 15);
+    // 16 filters, so the last two are nested
+    bench::<<A as Disjoint>::Only>("generated Only", &mut world, 1);
+    bench::<With<A>>("With<A>", &mut world, 1);
+}
@@ -0,0 +1,65 @@
+use bevy::{log::LogPlugin, prelude::*};
+use bevy_djqf::{disjoint, disjoint_queries};
+
+#[derive(Component, Debug, Default)]
+struct Player;
+
+#[derive(Component, Debug, Default)]
+struct Enemy;
+
+#[derive(Component, Debug, Default)]
+struct Crate;
+
+disjoint!(Player, Enemy, Crate);
+
+// Three mutable queries for `Transform` in one system, without a `ParamSet`
+disjoint_queries! {
+    struct Movers: &'static mut Transform {
+        player: Player,
+        enemies: Enemy,
+        crates: Crate,
+    }
+}
+
+fn main() {
+    App::new()
+        .add_plugins((MinimalPlugins, LogPlugin::default()))
+        .add_systems(Startup, spawn)
+        .add_systems(Update, (movement, exit_after_a_while))
+        .run();
+}
+
+fn spawn(mut commands: Commands) {
+    commands.spawn((Player, Transform::default()));
+    commands.spawn((Enemy, Transform::from_xyz(10.0, 0.0, 0.0)));
+    commands.spawn((Enemy, Transform::from_xyz(-10.0, 0.0, 0.0)));
+    commands.spawn((Crate, Transform::from_xyz(1.0, 0.0, 0.0)));
+}
+
+// The player walks right, pushing the crates ahead, while the enemies close in
+fn movement(mut movers: Movers) {
+    let Ok(mut player) = movers.player.get_single_mut() else {
+        return;
+    };
+    player.translation.x += 1.0;
+
+    for mut crate_transform in &mut movers.crates {
+        if crate_transform.translation.x <= player.translation.x {
+            crate_transform.translation.x = player.translation.x + 1.0;
+        }
+    }
+
+    for mut enemy in &mut movers.enemies {
+        let towards = player.translation - enemy.translation;
+        enemy.translation += towards.normalize_or_zero();
+    }
+
+    info!("player at {}", player.translation.x);
+}
+
+fn exit_after_a_while(mut frames: Local<u32>, mut exit: EventWriter<AppExit>) {
+    *frames += 1;
+    if *frames > 6 {
+        exit.send(AppExit::Success);
+    }
+}
@@ -0,0 +1,56 @@
+use bevy::{log::LogPlugin, prelude::*};
+use bevy_djqf::{make_disjoint_markers, per_variant_systems, Disjoint};
+
+macro_rules! type_template {
+    ($Name:ident) => {
+        #[derive(Component, Debug, Default)]
+        struct $Name;
+    };
+}
+make_disjoint_markers!(type_template for Player, FriendlyAi, EnemyAi, Wildlife, InanimateObject);
+
+// One update system for each "variant", which only sees the entities of that "variant",
+// so every system can mutate `Transform` without conflicting with the others
+macro_rules! move_template {
+    ($V:ty) => {
+        |time: Res<Time>, mut query: Query<&mut Transform, <$V as Disjoint>::Only>| {
+            let speed = (<$V as Disjoint>::INDEX + 1) as f32;
+            for mut transform in &mut query {
+                transform.translation.x += speed * time.delta_seconds();
+            }
+        }
+    };
+}
+
+fn main() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, LogPlugin::default()))
+        .add_systems(Startup, spawn)
+        .add_systems(Last, (log_positions, exit_after_a_while));
+    // The above would be equivalent to something like
+    // `.add_systems(Update, (move_player, move_friendly_ai, move_enemy_ai, ...))`,
+    // with one hand-written system for each "variant"
+    per_variant_systems!(app, Update, move_template for Player, FriendlyAi, EnemyAi, Wildlife, InanimateObject);
+    app.run();
+}
+
+fn spawn(mut commands: Commands) {
+    commands.spawn((Player, Transform::default()));
+    commands.spawn((FriendlyAi, Transform::default()));
+    commands.spawn((EnemyAi, Transform::default()));
+    commands.spawn((Wildlife, Transform::default()));
+    commands.spawn((InanimateObject, Transform::default()));
+}
+
+fn log_positions(query: Query<(Entity, &Transform), <Player as Disjoint>::Any>) {
+    for (entity, transform) in &query {
+        info!("{entity:?} is at x = {}", transform.translation.x);
+    }
+}
+
+fn exit_after_a_while(mut frames: Local<u32>, mut exit: EventWriter<AppExit>) {
+    *frames += 1;
+    if *frames > 3 {
+        exit.send(AppExit::Success);
+    }
+}
@@ -0,0 +1,46 @@
+//! Logs how many entities have each "variant", when F3 is pressed.
+//!
+//! Run with `cargo run --example variant_histogram`, then press F3 in the window.
+
+use bevy::{input::common_conditions::input_just_pressed, prelude::*};
+use bevy_djqf::{log_variant_histogram, DisjointCommandsExt};
+
+#[derive(bevy_djqf::Disjoint)]
+enum Unit {
+    Worker,
+    Soldier,
+    Scout,
+}
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_systems(Startup, spawn)
+        .add_systems(
+            Update,
+            (
+                promote.run_if(input_just_pressed(KeyCode::Space)),
+                log_variant_histogram::<Worker>.run_if(input_just_pressed(KeyCode::F3)),
+            ),
+        )
+        .run();
+}
+
+fn spawn(mut commands: Commands) {
+    commands.spawn(Camera2dBundle::default());
+    for _ in 0..10 {
+        commands.spawn(Worker);
+    }
+    for _ in 0..3 {
+        commands.spawn(Soldier);
+    }
+    commands.spawn(Scout);
+    info!("press Space to promote a worker to a soldier, and F3 to log the number of each unit");
+}
+
+// Change the counts, so that the next histogram is different
+fn promote(mut commands: Commands, workers: Query<Entity, With<Worker>>) {
+    if let Some(worker) = workers.iter().next() {
+        commands.set_variant::<Soldier>(worker);
+    }
+}
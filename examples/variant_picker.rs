@@ -0,0 +1,58 @@
+//! An inspector window with a dropdown for the "variant" of each entity.
+//!
+//! Run with `cargo run --example variant_picker --features egui`.
+
+use bevy::prelude::*;
+use bevy_djqf::{variant_picker, DisjointData, DisjointEnum};
+use bevy_egui::{egui, EguiContexts, EguiPlugin};
+
+#[derive(bevy_djqf::Disjoint)]
+enum GameObject {
+    Player,
+    Enemy,
+    Wall,
+}
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, EguiPlugin))
+        .add_systems(Startup, spawn)
+        .add_systems(Update, (inspector, log_players))
+        .run();
+}
+
+fn spawn(mut commands: Commands) {
+    commands.spawn(Camera2dBundle::default());
+    commands.spawn((Name::new("Hero"), Player));
+    commands.spawn((Name::new("Goblin"), Enemy));
+    commands.spawn((Name::new("Boulder"), Wall));
+    commands.spawn(Name::new("Untagged"));
+}
+
+fn inspector(
+    mut contexts: EguiContexts,
+    entities: Query<(Entity, &Name, DisjointData<GameObject>)>,
+    mut commands: Commands,
+) {
+    egui::Window::new("Variants").show(contexts.ctx_mut(), |ui| {
+        for (entity, name, value) in &entities {
+            ui.horizontal(|ui| {
+                ui.label(name.as_str());
+                let current = value.map(|value| value.index());
+                variant_picker::<<GameObject as DisjointEnum>::Group>(
+                    ui,
+                    entity,
+                    current,
+                    &mut commands,
+                );
+            });
+        }
+    });
+}
+
+// `Player` is a marker generated by the derive, so the picked "variants" are visible to ordinary queries
+fn log_players(players: Query<&Name, Added<Player>>) {
+    for name in &players {
+        info!("{name} is now the player");
+    }
+}
@@ -0,0 +1,69 @@
+use bevy::{log::LogPlugin, prelude::*};
+use bevy_djqf::{
+    make_disjoint_markers, Disjoint, DisjointCommandsExt, OnVariantChanged, VariantChangedPlugin,
+};
+
+macro_rules! type_template {
+    ($Name:ident) => {
+        #[derive(Component, Debug, Default)]
+        struct $Name;
+    };
+}
+make_disjoint_markers!(type_template for Idle, Walking, Running);
+
+const NAMES: [&str; 3] = [
+    <Idle as Disjoint>::NAME,
+    <Walking as Disjoint>::NAME,
+    <Running as Disjoint>::NAME,
+];
+
+fn main() {
+    App::new()
+        .add_plugins((MinimalPlugins, LogPlugin::default()))
+        // `Idle` is the first type, so it represents the group
+        .add_plugins(VariantChangedPlugin::<Idle>::default())
+        .add_systems(Startup, spawn)
+        .add_systems(Update, (cycle, exit_after_a_while))
+        .observe(log_transition)
+        .run();
+}
+
+fn spawn(mut commands: Commands) {
+    commands.spawn(Idle);
+}
+
+// Move every entity to the next "variant" each frame
+fn cycle(
+    mut commands: Commands,
+    idle: Query<Entity, <Idle as Disjoint>::Only>,
+    walking: Query<Entity, <Walking as Disjoint>::Only>,
+    running: Query<Entity, <Running as Disjoint>::Only>,
+) {
+    for entity in &idle {
+        commands.set_variant::<Walking>(entity);
+    }
+    for entity in &walking {
+        commands.set_variant::<Running>(entity);
+    }
+    for entity in &running {
+        commands.set_variant::<Idle>(entity);
+    }
+}
+
+fn log_transition(trigger: Trigger<OnVariantChanged<Idle>>) {
+    let event = trigger.event();
+    let name = |index: Option<usize>| index.map_or("nothing", |index| NAMES[index]);
+    info!(
+        "{:?} changed from {} to {}",
+        event.entity,
+        name(event.old),
+        name(event.new)
+    );
+}
+
+fn exit_after_a_while(mut frames: Local<u32>, mut exit: EventWriter<AppExit>) {
+    *frames += 1;
+    if *frames > 6 {
+        exit.send(AppExit::Success);
+    }
+}
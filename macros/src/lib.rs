@@ -0,0 +1,396 @@
+//! Derive macros for [`bevy_djqf`](https://crates.io/crates/bevy_djqf). Use them through that crate.
+
+use proc_macro::TokenStream;
+use quote::{format_ident, quote, ToTokens};
+use syn::{
+    parse::{Parse, ParseStream},
+    parse_macro_input,
+    punctuated::Punctuated,
+    spanned::Spanned,
+    Data, DeriveInput, Error, Fields, Ident, MetaNameValue, Token, Type, Visibility,
+};
+
+/// Generate a marker component for each variant of a fieldless enum, and make them disjoint.
+/// The enum implements `DisjointEnum`, to convert between its values and the markers, and gets `ALL` and `INFO` constants.
+/// It implements `Display` with the name of each variant.
+/// It also converts to and from its index as a `u8`, or a `u16` if it has more than 256 variants.
+/// A `{Enum}Mask` type is generated for sets of values.
+/// `{Enum}Any` and `{Enum}None` type aliases are generated for the filters that match any or none of the markers.
+///
+/// See the `bevy_djqf` crate for documentation.
+#[proc_macro_derive(Disjoint)]
+pub fn derive_disjoint(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    derive(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn derive(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let Data::Enum(data) = &input.data else {
+        return Err(Error::new(
+            input.ident.span(),
+            "`#[derive(Disjoint)]` can only be used on enums",
+        ));
+    };
+    if !input.generics.params.is_empty() {
+        return Err(Error::new(
+            input.generics.span(),
+            "`#[derive(Disjoint)]` does not support generic enums",
+        ));
+    }
+    if data.variants.len() < 2 {
+        return Err(Error::new(
+            input.ident.span(),
+            "`#[derive(Disjoint)]` requires at least two variants",
+        ));
+    }
+
+    let vis = &input.vis;
+    let mut markers = Vec::new();
+    let mut names = Vec::new();
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(Error::new(
+                variant.fields.span(),
+                "`#[derive(Disjoint)]` only supports unit variants",
+            ));
+        }
+        let name = &variant.ident;
+        // doc comments and `#[deprecated]` are kept on the markers
+        let docs = variant
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("doc") || attr.path().is_ident("deprecated"));
+        let reflect = cfg!(feature = "reflect").then(|| {
+            quote! {
+                #[derive(::bevy_djqf::__private::Reflect)]
+            }
+        });
+        markers.push(quote! {
+            #(#docs)*
+            #[derive(
+                ::bevy_djqf::__private::Component,
+                ::core::fmt::Debug,
+                ::core::default::Default,
+            )]
+            #reflect
+            #vis struct #name;
+        });
+        names.push(name);
+    }
+
+    let ident = &input.ident;
+    let first = names[0];
+    let indices = 0..names.len();
+    let count = names.len();
+    // the smallest unsigned integer that fits every index
+    let repr = if count <= 1 << 8 {
+        quote!(u8)
+    } else if count <= 1 << 16 {
+        quote!(u16)
+    } else {
+        return Err(Error::new(
+            input.ident.span(),
+            "`#[derive(Disjoint)]` supports at most 65536 variants",
+        ));
+    };
+    let all_doc = format!("Every value of [`{ident}`], in the order of the variants.");
+    let info_doc = format!("The metadata of the group generated for [`{ident}`].");
+    let any = format_ident!("{}Any", ident);
+    let any_doc = format!(
+        "Entities with any \"variant\" of [`{ident}`]. The same as `<{first} as Disjoint>::Any`, for any member."
+    );
+    let mask = format_ident!("{}Mask", ident);
+    let mask_doc = format!(
+        "A set of values of [`{ident}`], with one bit for each \"variant\", e.g. for a subset of \"variants\" that is configured at runtime."
+    );
+    let words = count.div_ceil(64);
+    let all_bits = (0..words).map(|word| {
+        let bits = (count - word * 64).min(64);
+        if bits == 64 {
+            u64::MAX
+        } else {
+            (1 << bits) - 1
+        }
+    });
+    let none = format_ident!("{}None", ident);
+    let none_doc = format!(
+        "Entities with no \"variant\" of [`{ident}`]. The same as `<{first} as Disjoint>::None`, for any member."
+    );
+
+    Ok(quote! {
+        #(#markers)*
+
+        ::bevy_djqf::disjoint!(#(#names),*);
+
+        #[doc = #any_doc]
+        #[allow(deprecated)]
+        #vis type #any = <#first as ::bevy_djqf::Disjoint>::Any;
+
+        #[doc = #none_doc]
+        #[allow(deprecated)]
+        #vis type #none = <#first as ::bevy_djqf::Disjoint>::None;
+
+        #[allow(deprecated)]
+        impl #ident {
+            #[doc = #all_doc]
+            #vis const ALL: [Self; #count] = [#(Self::#names),*];
+
+            #[doc = #info_doc]
+            #vis const INFO: ::bevy_djqf::DisjointInfo = <#first as ::bevy_djqf::DisjointGroup>::INFO;
+        }
+
+        #[allow(deprecated)]
+        impl ::core::fmt::Display for #ident {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.pad(<#first as ::bevy_djqf::DisjointGroup>::NAMES[::bevy_djqf::DisjointEnum::index(self)])
+            }
+        }
+
+        #[allow(deprecated)]
+        impl ::bevy_djqf::DisjointEnum for #ident {
+            type Group = #first;
+
+            fn index(&self) -> usize {
+                match self {
+                    #(Self::#names => <#names as ::bevy_djqf::Disjoint>::INDEX,)*
+                }
+            }
+
+            fn from_index(index: usize) -> ::core::option::Option<Self> {
+                match index {
+                    #(#indices => ::core::option::Option::Some(Self::#names),)*
+                    _ => ::core::option::Option::None,
+                }
+            }
+        }
+
+        #[doc = #mask_doc]
+        #[derive(
+            ::core::clone::Clone,
+            ::core::marker::Copy,
+            ::core::fmt::Debug,
+            ::core::default::Default,
+            ::core::cmp::PartialEq,
+            ::core::cmp::Eq,
+            ::core::hash::Hash,
+        )]
+        #vis struct #mask {
+            bits: [u64; #words],
+        }
+
+        impl #mask {
+            /// The set with no values.
+            #vis const EMPTY: Self = Self { bits: [0; #words] };
+
+            /// The set with every value.
+            #vis const ALL: Self = Self { bits: [#(#all_bits),*] };
+
+            /// Whether the value with this `INDEX` is in the set.
+            #vis fn contains(&self, index: usize) -> bool {
+                index < #count && self.bits[index / 64] & (1 << (index % 64)) != 0
+            }
+
+            /// Add the value with this `INDEX` to the set.
+            ///
+            /// # Panics
+            /// Panics if there is no value at `index`.
+            #vis fn insert(&mut self, index: usize) {
+                ::core::assert!(index < #count, "there is no value at index {index}, there are only {}", #count);
+                self.bits[index / 64] |= 1 << (index % 64);
+            }
+
+            /// Remove the value with this `INDEX` from the set, if it is in it.
+            #vis fn remove(&mut self, index: usize) {
+                if index < #count {
+                    self.bits[index / 64] &= !(1 << (index % 64));
+                }
+            }
+
+            /// The number of values in the set.
+            #vis fn len(&self) -> usize {
+                self.bits.iter().map(|word| word.count_ones() as usize).sum()
+            }
+
+            /// Whether the set has no values.
+            #vis fn is_empty(&self) -> bool {
+                *self == Self::EMPTY
+            }
+
+            /// The `INDEX` of every value in the set, in order.
+            #vis fn iter(&self) -> impl ::core::iter::Iterator<Item = usize> + '_ {
+                (0..#count).filter(|&index| self.contains(index))
+            }
+
+            /// Filter the query to entities with any of the "variants" in the set. Nothing matches if it is empty.
+            ///
+            /// See `DynamicDisjoint::any_of`.
+            // the type parameters aren't hygienic, so they mustn't shadow a marker named e.g. `D`
+            #vis fn filter<__D, __F>(&self, builder: &mut ::bevy_djqf::__private::query::QueryBuilder<__D, __F>)
+            where
+                __D: ::bevy_djqf::__private::query::QueryData,
+                __F: ::bevy_djqf::__private::query::QueryFilter,
+            {
+                ::bevy_djqf::DynamicDisjoint::from_group::<#first>(builder.world_mut())
+                    .any_of(builder, self.iter());
+            }
+        }
+
+        impl ::core::convert::From<#ident> for #mask {
+            fn from(value: #ident) -> Self {
+                let mut mask = Self::EMPTY;
+                mask.insert(::bevy_djqf::DisjointEnum::index(&value));
+                mask
+            }
+        }
+
+        impl ::core::iter::FromIterator<#ident> for #mask {
+            fn from_iter<__I: ::core::iter::IntoIterator<Item = #ident>>(values: __I) -> Self {
+                let mut mask = Self::EMPTY;
+                for value in values {
+                    mask.insert(::bevy_djqf::DisjointEnum::index(&value));
+                }
+                mask
+            }
+        }
+
+        impl ::core::convert::TryFrom<#repr> for #ident {
+            type Error = ::bevy_djqf::OutOfRange;
+
+            fn try_from(index: #repr) -> ::core::result::Result<Self, Self::Error> {
+                let index = ::core::primitive::usize::from(index);
+                <Self as ::bevy_djqf::DisjointEnum>::from_index(index)
+                    .ok_or(::bevy_djqf::OutOfRange { index, count: #count })
+            }
+        }
+
+        impl ::core::convert::From<#ident> for #repr {
+            fn from(value: #ident) -> Self {
+                // every index fits, see above
+                <#ident as ::bevy_djqf::DisjointEnum>::index(&value) as #repr
+            }
+        }
+    })
+}
+
+/// Generate the `{Name}Only`, `{Name}Other` and `{Name}Any` type aliases for `make_disjoint_markers!`.
+///
+/// The input is `vis; A, B; aliases` or `vis; A, B; aliases(prefix = "..", suffix = "..")`.
+#[doc(hidden)]
+#[proc_macro]
+pub fn disjoint_aliases(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as AliasesInput);
+    aliases(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+struct AliasesInput {
+    vis: Visibility,
+    names: Punctuated<Ident, Token![,]>,
+    prefix: String,
+    suffix: String,
+}
+
+impl Parse for AliasesInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let vis = input.parse()?;
+        input.parse::<Token![;]>()?;
+        let mut names = Punctuated::new();
+        while !input.peek(Token![;]) {
+            names.push_value(input.parse()?);
+            if input.peek(Token![;]) {
+                break;
+            }
+            names.push_punct(input.parse()?);
+        }
+        input.parse::<Token![;]>()?;
+
+        let flag: Ident = input.parse()?;
+        if flag != "aliases" {
+            return Err(Error::new(
+                flag.span(),
+                "expected `aliases` or `aliases(prefix = \"..\", suffix = \"..\")`",
+            ));
+        }
+        let mut prefix = String::new();
+        let mut suffix = String::new();
+        if !input.is_empty() {
+            let content;
+            syn::parenthesized!(content in input);
+            let options = Punctuated::<MetaNameValue, Token![,]>::parse_terminated(&content)?;
+            for option in options {
+                let syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Str(value),
+                    ..
+                }) = &option.value
+                else {
+                    return Err(Error::new(option.value.span(), "expected a string"));
+                };
+                if option.path.is_ident("prefix") {
+                    prefix = value.value();
+                } else if option.path.is_ident("suffix") {
+                    suffix = value.value();
+                } else {
+                    return Err(Error::new(
+                        option.path.span(),
+                        "expected `prefix` or `suffix`",
+                    ));
+                }
+            }
+        }
+        Ok(Self {
+            vis,
+            names,
+            prefix,
+            suffix,
+        })
+    }
+}
+
+fn aliases(input: AliasesInput) -> syn::Result<proc_macro2::TokenStream> {
+    let vis = &input.vis;
+    let mut aliases = Vec::new();
+    for name in &input.names {
+        for kind in ["Only", "Other", "Any"] {
+            let alias = format!("{}{name}{kind}{}", input.prefix, input.suffix);
+            let alias: Ident = syn::parse_str(&alias).map_err(|_| {
+                Error::new(name.span(), format!("`{alias}` is not a valid type name"))
+            })?;
+            let kind = format_ident!("{}", kind);
+            let doc = format!("`<{name} as Disjoint>::{kind}`");
+            aliases.push(quote! {
+                #[doc = #doc]
+                #[allow(deprecated)]
+                #vis type #alias = <#name as ::bevy_djqf::Disjoint>::#kind;
+            });
+        }
+    }
+    Ok(quote! {
+        #(#aliases)*
+    })
+}
+
+/// Check that no type is provided more than once to `disjoint!`.
+///
+/// The input is the comma-separated list of types, and there is no output unless a type is repeated.
+#[doc(hidden)]
+#[proc_macro]
+pub fn disjoint_unique(input: TokenStream) -> TokenStream {
+    let types = parse_macro_input!(input with Punctuated::<Type, Token![,]>::parse_terminated);
+    let mut seen = Vec::new();
+    for ty in &types {
+        let name = ty.to_token_stream().to_string();
+        if seen.contains(&name) {
+            return Error::new(
+                ty.span(),
+                format!("`{name}` is provided more than once to `disjoint!`"),
+            )
+            .into_compile_error()
+            .into();
+        }
+        seen.push(name);
+    }
+    TokenStream::new()
+}
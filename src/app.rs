@@ -0,0 +1,225 @@
+use std::marker::PhantomData;
+
+use bevy_app::App;
+
+#[cfg(feature = "diagnostics")]
+use crate::DisjointDiagnosticsPlugin;
+#[cfg(feature = "reflect")]
+use crate::ReflectMembers;
+use crate::{DisjointEnforcePlugin, DisjointGroup, OnViolation};
+
+/// What [`DisjointAppExt::add_disjoint_group_with`] sets up for the group `G`.
+///
+/// The default enforces the group with [`DisjointEnforcePlugin`] in debug builds, panicking on violations,
+/// and doesn't record diagnostics. With the `reflect` feature, it also registers the "variants" for reflection,
+/// if they all implement `Reflect`, as in `DisjointReflectAppExt::register_disjoint_group`.
+/// A group of generic types is only registered if its bounds require the members to implement `Reflect`,
+/// since whether they do is decided once for every type argument. Otherwise, use `register_disjoint_group` for it.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::Component;
+/// use bevy_djqf::{disjoint, DisjointGroupConfig, OnViolation};
+///
+/// #[derive(Component, Debug, Default)]
+/// struct A;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct B;
+///
+/// disjoint!(A, B);
+///
+/// let config = DisjointGroupConfig::<A>::new().enforce(OnViolation::Warn);
+/// assert_eq!(config.on_violation, Some(OnViolation::Warn));
+///
+/// let config = DisjointGroupConfig::<A>::new().without_enforcement();
+/// assert_eq!(config.on_violation, None);
+/// ```
+pub struct DisjointGroupConfig<G> {
+    /// What to do when an entity has more than one "variant", or `None` to not add [`DisjointEnforcePlugin`].
+    pub on_violation: Option<OnViolation>,
+    /// Whether to add [`DisjointDiagnosticsPlugin`]. Requires the `diagnostics` feature.
+    #[cfg(feature = "diagnostics")]
+    pub diagnostics: bool,
+    /// Whether to register the "variants" for reflection, if they all implement `Reflect`. Requires the `reflect` feature.
+    #[cfg(feature = "reflect")]
+    pub reflect: bool,
+    _group: PhantomData<fn() -> G>,
+}
+
+// manual impls, so that `G` doesn't need to implement these traits
+impl<G> Clone for DisjointGroupConfig<G> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<G> Copy for DisjointGroupConfig<G> {}
+
+impl<G> PartialEq for DisjointGroupConfig<G> {
+    fn eq(&self, other: &Self) -> bool {
+        #[cfg(feature = "diagnostics")]
+        if self.diagnostics != other.diagnostics {
+            return false;
+        }
+        #[cfg(feature = "reflect")]
+        if self.reflect != other.reflect {
+            return false;
+        }
+        self.on_violation == other.on_violation
+    }
+}
+
+impl<G> Eq for DisjointGroupConfig<G> {}
+
+impl<G> std::fmt::Debug for DisjointGroupConfig<G> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut debug = f.debug_struct("DisjointGroupConfig");
+        debug.field("on_violation", &self.on_violation);
+        #[cfg(feature = "diagnostics")]
+        debug.field("diagnostics", &self.diagnostics);
+        #[cfg(feature = "reflect")]
+        debug.field("reflect", &self.reflect);
+        debug.finish()
+    }
+}
+
+impl<G> Default for DisjointGroupConfig<G> {
+    fn default() -> Self {
+        Self {
+            on_violation: Some(OnViolation::default()),
+            #[cfg(feature = "diagnostics")]
+            diagnostics: false,
+            #[cfg(feature = "reflect")]
+            reflect: true,
+            _group: PhantomData,
+        }
+    }
+}
+
+impl<G> DisjointGroupConfig<G> {
+    /// The default configuration.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enforce the group in debug builds, with the given behaviour on violations.
+    pub fn enforce(mut self, on_violation: OnViolation) -> Self {
+        self.on_violation = Some(on_violation);
+        self
+    }
+
+    /// Don't enforce the group.
+    pub fn without_enforcement(mut self) -> Self {
+        self.on_violation = None;
+        self
+    }
+
+    /// Record the number of entities with each "variant". Requires the `diagnostics` feature.
+    #[cfg(feature = "diagnostics")]
+    pub fn diagnostics(mut self, diagnostics: bool) -> Self {
+        self.diagnostics = diagnostics;
+        self
+    }
+
+    /// Register every "variant" of the group for reflection, as in
+    /// [`register_disjoint_group`](crate::DisjointReflectAppExt::register_disjoint_group).
+    /// This is already the default, but unlike the default, it doesn't compile if a "variant" doesn't implement `Reflect`.
+    /// See [`DisjointGroupConfig`] for groups of generic types.
+    /// Requires the `reflect` feature.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::{App, AppTypeRegistry, Component, Reflect};
+    /// use bevy_djqf::{disjoint, DisjointAppExt, DisjointGroupConfig};
+    ///
+    /// #[derive(Component, Reflect, Debug, Default)]
+    /// struct A;
+    ///
+    /// #[derive(Component, Reflect, Debug, Default)]
+    /// struct B;
+    ///
+    /// disjoint!(A, B);
+    ///
+    /// let mut app = App::new();
+    /// app.add_disjoint_group_with::<A>(DisjointGroupConfig::new().reflect());
+    ///
+    /// let registry = app.world().resource::<AppTypeRegistry>().read();
+    /// assert!(registry.contains(std::any::TypeId::of::<B>()));
+    /// ```
+    #[cfg(feature = "reflect")]
+    pub fn reflect(mut self) -> Self
+    where
+        G: ReflectMembers,
+    {
+        self.reflect = true;
+        self
+    }
+
+    /// Don't register the "variants" for reflection. Requires the `reflect` feature.
+    #[cfg(feature = "reflect")]
+    pub fn without_reflection(mut self) -> Self {
+        self.reflect = false;
+        self
+    }
+}
+
+/// Extension methods on [`App`] for setting up the runtime behaviour of a group in one call.
+pub trait DisjointAppExt {
+    /// Set up the group `G` with the default [`DisjointGroupConfig`].
+    ///
+    /// This adds [`DisjointEnforcePlugin`] for `G`, which does nothing in release builds.
+    /// With the `reflect` feature, it also registers the "variants" for reflection, if they all implement `Reflect`.
+    ///
+    /// # Example
+    /// ```should_panic
+    /// # use bevy::prelude::{App, Component};
+    /// use bevy_djqf::{disjoint, DisjointAppExt};
+    ///
+    /// #[derive(Component, Debug, Default)]
+    /// struct A;
+    ///
+    /// #[derive(Component, Debug, Default)]
+    /// struct B;
+    ///
+    /// disjoint!(A, B);
+    ///
+    /// let mut app = App::new();
+    /// app.add_disjoint_group::<A>();
+    /// app.world_mut().spawn((A, B));
+    ///
+    /// // panics: the entity has both `A` and `B`
+    /// app.update();
+    /// ```
+    fn add_disjoint_group<G: DisjointGroup + 'static>(&mut self) -> &mut Self;
+
+    /// Set up the group `G` as in [`add_disjoint_group`](Self::add_disjoint_group), with the given configuration.
+    fn add_disjoint_group_with<G: DisjointGroup + 'static>(
+        &mut self,
+        config: DisjointGroupConfig<G>,
+    ) -> &mut Self;
+}
+
+impl DisjointAppExt for App {
+    fn add_disjoint_group<G: DisjointGroup + 'static>(&mut self) -> &mut Self {
+        self.add_disjoint_group_with::<G>(DisjointGroupConfig::default())
+    }
+
+    fn add_disjoint_group_with<G: DisjointGroup + 'static>(
+        &mut self,
+        config: DisjointGroupConfig<G>,
+    ) -> &mut Self {
+        #[cfg(feature = "reflect")]
+        if config.reflect {
+            G::__register_reflected(self);
+        }
+        if let Some(on_violation) = config.on_violation {
+            self.add_plugins(DisjointEnforcePlugin::<G>::new(on_violation));
+        }
+        #[cfg(feature = "diagnostics")]
+        if config.diagnostics {
+            self.add_plugins(DisjointDiagnosticsPlugin::<G>::default());
+        }
+        self
+    }
+}
@@ -0,0 +1,874 @@
+use bevy_ecs::{
+    bundle::Bundle,
+    component::Component,
+    entity::Entity,
+    system::{Commands, EntityCommands},
+    world::{EntityRef, EntityWorldMut, World},
+};
+
+use crate::{
+    visitors::{has_member, remove_others, variant_of},
+    DefaultVariant, Disjoint, DisjointEnum, DisjointGroup, GroupMember, Subvariant,
+};
+
+/// Extension methods on [`Commands`] for changing the "variant" of an entity.
+pub trait DisjointCommandsExt {
+    /// Insert the "variant" `V` on the entity, and remove every other "variant" of the same "enum".
+    ///
+    /// Removing a "variant" the entity doesn't have is not an error.
+    /// If the entity doesn't exist when the command is applied, this does nothing.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::{Commands, Component, World};
+    /// # use bevy::ecs::world::CommandQueue;
+    /// use bevy_djqf::{disjoint, DisjointCommandsExt};
+    ///
+    /// #[derive(Component, Debug, Default)]
+    /// struct A;
+    ///
+    /// #[derive(Component, Debug, Default)]
+    /// struct B;
+    ///
+    /// disjoint!(A, B);
+    ///
+    /// let mut world = World::new();
+    /// let entity = world.spawn(A).id();
+    ///
+    /// let mut queue = CommandQueue::default();
+    /// Commands::new(&mut queue, &world).set_variant::<B>(entity);
+    /// queue.apply(&mut world);
+    ///
+    /// assert!(!world.entity(entity).contains::<A>());
+    /// assert!(world.entity(entity).contains::<B>());
+    /// ```
+    fn set_variant<V: Disjoint + Component + Default>(&mut self, entity: Entity);
+
+    /// Spawn an entity with the `bundle` and the "variant" `V`, and no other "variant" of the same "enum".
+    ///
+    /// Any other "variant" in the `bundle` is removed, so the entity always ends up with exactly one.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::{Commands, Component, Transform, World};
+    /// # use bevy::ecs::world::CommandQueue;
+    /// use bevy_djqf::{disjoint, Disjoint, DisjointCommandsExt};
+    ///
+    /// #[derive(Component, Debug, Default)]
+    /// struct A;
+    ///
+    /// #[derive(Component, Debug, Default)]
+    /// struct B;
+    ///
+    /// disjoint!(A, B);
+    ///
+    /// let mut world = World::new();
+    /// let mut queue = CommandQueue::default();
+    /// // `A` is removed, even though it was in the bundle
+    /// let entity = Commands::new(&mut queue, &world)
+    ///     .spawn_variant::<B>((Transform::default(), A))
+    ///     .id();
+    /// queue.apply(&mut world);
+    ///
+    /// let mut only_b = world.query_filtered::<&Transform, <B as Disjoint>::Only>();
+    /// assert_eq!(only_b.iter(&world).count(), 1);
+    /// assert!(!world.entity(entity).contains::<A>());
+    /// ```
+    fn spawn_variant<V: Disjoint + Component + Default>(
+        &mut self,
+        bundle: impl Bundle,
+    ) -> EntityCommands<'_>;
+
+    /// Spawn an entity with the `bundle` and the [default](DefaultVariant) "variant" of the group `G`,
+    /// and no other "variant" of the same "enum".
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::{Commands, Component, Transform, World};
+    /// # use bevy::ecs::world::CommandQueue;
+    /// use bevy_djqf::{disjoint, DefaultVariant, DisjointCommandsExt};
+    ///
+    /// #[derive(Component, Debug, Default)]
+    /// struct Wall;
+    ///
+    /// #[derive(Component, Debug, Default)]
+    /// struct Player;
+    ///
+    /// #[derive(Component, Debug, Default)]
+    /// struct Enemy;
+    ///
+    /// disjoint!(Wall, #[default] Player, Enemy);
+    ///
+    /// assert_eq!(<Wall as DefaultVariant>::DEFAULT_INDEX, 1);
+    ///
+    /// let mut world = World::new();
+    /// let mut queue = CommandQueue::default();
+    /// let entity = Commands::new(&mut queue, &world)
+    ///     .spawn_default::<Wall>(Transform::default())
+    ///     .id();
+    /// queue.apply(&mut world);
+    ///
+    /// assert!(world.entity(entity).contains::<Player>());
+    /// assert!(!world.entity(entity).contains::<Wall>());
+    /// ```
+    fn spawn_default<G: DefaultVariant>(&mut self, bundle: impl Bundle) -> EntityCommands<'_>;
+
+    /// Remove every "variant" of the group `G` from the entity, so that it matches [`None`](Disjoint::None).
+    ///
+    /// Removing a "variant" the entity doesn't have is not an error.
+    /// If the entity doesn't exist when the command is applied, this does nothing.
+    fn clear_variant<G: DisjointGroup>(&mut self, entity: Entity);
+
+    /// Insert the "variant" `V` on every entity, and remove every other "variant" of the same "enum",
+    /// as a single command rather than one command per entity.
+    ///
+    /// Removing a "variant" an entity doesn't have is not an error.
+    /// Entities that don't exist when the command is applied are skipped.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::{Commands, Component, World};
+    /// # use bevy::ecs::world::CommandQueue;
+    /// use bevy_djqf::{disjoint, Disjoint, DisjointCommandsExt};
+    ///
+    /// #[derive(Component, Debug, Default)]
+    /// struct A;
+    ///
+    /// #[derive(Component, Debug, Default)]
+    /// struct B;
+    ///
+    /// #[derive(Component, Debug, Default)]
+    /// struct C;
+    ///
+    /// disjoint!(A, B, C);
+    ///
+    /// let mut world = World::new();
+    /// // a mix of entities with `A`, `B`, and no "variant"
+    /// let mut entities = Vec::new();
+    /// entities.extend(world.spawn_batch((0..2000).map(|_| A)));
+    /// entities.extend(world.spawn_batch((0..2000).map(|_| B)));
+    /// entities.extend((0..1000).map(|_| world.spawn_empty().id()));
+    ///
+    /// let mut queue = CommandQueue::default();
+    /// Commands::new(&mut queue, &world).set_variant_batch::<C>(entities);
+    /// queue.apply(&mut world);
+    ///
+    /// let mut only_c = world.query_filtered::<(), <C as Disjoint>::Only>();
+    /// assert_eq!(only_c.iter(&world).count(), 5000);
+    /// ```
+    fn set_variant_batch<V: Disjoint + Component + Default>(
+        &mut self,
+        entities: impl IntoIterator<Item = Entity>,
+    );
+
+    /// Exchange the "variants" of the group `G` between the entities `a` and `b`, e.g. for a "mind swap".
+    ///
+    /// Each entity gets the "variant" the other one had, with its [`Default`] value, and nothing else from the group.
+    /// If only one of them has a "variant", the other one ends up with no "variant".
+    /// If either entity doesn't exist when the command is applied, this does nothing.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::{Commands, Component, World};
+    /// # use bevy::ecs::world::CommandQueue;
+    /// use bevy_djqf::{disjoint, DisjointCommandsExt};
+    ///
+    /// #[derive(Component, Debug, Default)]
+    /// struct A;
+    ///
+    /// #[derive(Component, Debug, Default)]
+    /// struct B;
+    ///
+    /// disjoint!(A, B);
+    ///
+    /// let mut world = World::new();
+    /// let x = world.spawn(A).id();
+    /// let y = world.spawn(B).id();
+    ///
+    /// let mut queue = CommandQueue::default();
+    /// Commands::new(&mut queue, &world).swap_variants::<A>(x, y);
+    /// queue.apply(&mut world);
+    ///
+    /// assert!(world.entity(x).contains::<B>() && !world.entity(x).contains::<A>());
+    /// assert!(world.entity(y).contains::<A>() && !world.entity(y).contains::<B>());
+    /// ```
+    fn swap_variants<G: DefaultMembers>(&mut self, a: Entity, b: Entity);
+
+    /// Spawn `n` entities, each with the "variant" of the group `G` at the index `f` returns for its position,
+    /// e.g. `|i| i % COUNT` to cycle through the "variants" for a test or a demo, and return them in order.
+    ///
+    /// Each entity gets the [`Default`] value of its "variant", and nothing else from the group.
+    ///
+    /// # Errors
+    /// Returns [`OutOfRange`] for the first index `f` returns that has no "variant", without spawning anything.
+    /// `f` is called for every position before any entity is spawned.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::{Commands, Component, World};
+    /// # use bevy::ecs::world::CommandQueue;
+    /// use bevy_djqf::{disjoint, Disjoint, DisjointCommandsExt, DisjointGroup, OutOfRange};
+    ///
+    /// #[derive(Component, Debug, Default)]
+    /// struct Player;
+    ///
+    /// #[derive(Component, Debug, Default)]
+    /// struct Ally;
+    ///
+    /// #[derive(Component, Debug, Default)]
+    /// struct Enemy;
+    ///
+    /// disjoint!(Player, Ally, Enemy);
+    ///
+    /// let mut world = World::new();
+    /// let mut queue = CommandQueue::default();
+    /// let mut commands = Commands::new(&mut queue, &world);
+    /// let entities = commands
+    ///     .spawn_variants_from_fn::<Player>(6, |i| i % <Player as DisjointGroup>::COUNT)
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     commands.spawn_variants_from_fn::<Player>(6, |i| i).err(),
+    ///     Some(OutOfRange { index: 3, count: 3 }),
+    /// );
+    /// queue.apply(&mut world);
+    ///
+    /// assert!(world.entity(entities[1]).contains::<Ally>());
+    /// let mut only_enemy = world.query_filtered::<(), <Enemy as Disjoint>::Only>();
+    /// assert_eq!(only_enemy.iter(&world).count(), 2);
+    /// ```
+    fn spawn_variants_from_fn<G: DefaultMembers>(
+        &mut self,
+        n: usize,
+        f: impl FnMut(usize) -> usize,
+    ) -> Result<Vec<Entity>, OutOfRange>;
+}
+
+impl DisjointCommandsExt for Commands<'_, '_> {
+    fn set_variant<V: Disjoint + Component + Default>(&mut self, entity: Entity) {
+        self.add(move |world: &mut World| {
+            if let Some(mut entity) = world.get_entity_mut(entity) {
+                set_variant::<V>(&mut entity);
+            }
+        });
+    }
+
+    fn spawn_variant<V: Disjoint + Component + Default>(
+        &mut self,
+        bundle: impl Bundle,
+    ) -> EntityCommands<'_> {
+        let mut entity = self.spawn(bundle);
+        entity.set_variant::<V>();
+        entity
+    }
+
+    fn spawn_default<G: DefaultVariant>(&mut self, bundle: impl Bundle) -> EntityCommands<'_> {
+        self.spawn_variant::<G::Default>(bundle)
+    }
+
+    fn clear_variant<G: DisjointGroup>(&mut self, entity: Entity) {
+        self.add(move |world: &mut World| {
+            if let Some(mut entity) = world.get_entity_mut(entity) {
+                remove_others::<G>(&mut entity, None);
+            }
+        });
+    }
+
+    fn set_variant_batch<V: Disjoint + Component + Default>(
+        &mut self,
+        entities: impl IntoIterator<Item = Entity>,
+    ) {
+        let entities: Vec<Entity> = entities.into_iter().collect();
+        self.add(move |world: &mut World| {
+            for entity in entities {
+                if let Some(mut entity) = world.get_entity_mut(entity) {
+                    set_variant::<V>(&mut entity);
+                }
+            }
+        });
+    }
+
+    fn swap_variants<G: DefaultMembers>(&mut self, a: Entity, b: Entity) {
+        self.add(move |world: &mut World| {
+            let (Some(entity_a), Some(entity_b)) = (world.get_entity(a), world.get_entity(b))
+            else {
+                return;
+            };
+            let (variant_a, variant_b) = (variant_of::<G>(&entity_a), variant_of::<G>(&entity_b));
+            set_variant_index::<G>(&mut world.entity_mut(a), variant_b);
+            set_variant_index::<G>(&mut world.entity_mut(b), variant_a);
+        });
+    }
+
+    fn spawn_variants_from_fn<G: DefaultMembers>(
+        &mut self,
+        n: usize,
+        f: impl FnMut(usize) -> usize,
+    ) -> Result<Vec<Entity>, OutOfRange> {
+        let count = G::COUNT;
+        let indices: Vec<usize> = (0..n).map(f).collect();
+        if let Some(&index) = indices.iter().find(|&&index| !has_member::<G>(index)) {
+            return Err(OutOfRange { index, count });
+        }
+        Ok(indices
+            .into_iter()
+            .map(|index| {
+                self.spawn_empty()
+                    .add(move |mut entity: EntityWorldMut| {
+                        set_variant_index::<G>(&mut entity, Some(index));
+                    })
+                    .id()
+            })
+            .collect())
+    }
+}
+
+/// Extension methods on [`EntityCommands`] for changing the "variant" of the entity.
+pub trait DisjointEntityCommandsExt {
+    /// Insert the "variant" `V` on this entity, and remove every other "variant" of the same "enum".
+    ///
+    /// Removing a "variant" the entity doesn't have is not an error.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::{Commands, Component, World};
+    /// # use bevy::ecs::world::CommandQueue;
+    /// use bevy_djqf::{disjoint, DisjointEntityCommandsExt};
+    ///
+    /// #[derive(Component, Debug, Default)]
+    /// struct A;
+    ///
+    /// #[derive(Component, Debug, Default)]
+    /// struct B;
+    ///
+    /// disjoint!(A, B);
+    ///
+    /// let mut world = World::new();
+    /// let entity = world.spawn(A).id();
+    ///
+    /// let mut queue = CommandQueue::default();
+    /// Commands::new(&mut queue, &world).entity(entity).set_variant::<B>();
+    /// queue.apply(&mut world);
+    ///
+    /// assert!(!world.entity(entity).contains::<A>());
+    /// assert!(world.entity(entity).contains::<B>());
+    /// ```
+    fn set_variant<V: Disjoint + Component + Default>(&mut self) -> &mut Self;
+
+    /// Insert `value` as the "variant" of this entity, and remove every other "variant" of the same "enum".
+    ///
+    /// This is [`set_variant`](Self::set_variant) for markers that don't implement [`Default`], e.g. markers with fields.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::{Commands, Component, World};
+    /// # use bevy::ecs::world::CommandQueue;
+    /// use bevy_djqf::{disjoint, DisjointEntityCommandsExt};
+    ///
+    /// #[derive(Component, Debug)]
+    /// struct Team(u32);
+    ///
+    /// #[derive(Component, Debug)]
+    /// struct Neutral;
+    ///
+    /// disjoint!(Team, Neutral);
+    ///
+    /// let mut world = World::new();
+    /// let entity = world.spawn(Neutral).id();
+    ///
+    /// let mut queue = CommandQueue::default();
+    /// Commands::new(&mut queue, &world).entity(entity).insert_variant(Team(2));
+    /// queue.apply(&mut world);
+    ///
+    /// assert!(!world.entity(entity).contains::<Neutral>());
+    /// assert_eq!(world.get::<Team>(entity).unwrap().0, 2);
+    /// ```
+    fn insert_variant<V: Disjoint + Component>(&mut self, value: V) -> &mut Self;
+
+    /// Swap between the "variants" `A` and `B` on this entity, e.g. for on/off pairs like `Active` and `Inactive`.
+    ///
+    /// If the entity has `A`, this is [`set_variant::<B>`](Self::set_variant), and otherwise it is
+    /// [`set_variant::<A>`](Self::set_variant), so an entity with neither (or with another "variant" of the group) gets `A`.
+    /// `A` and `B` must be members of the same group.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::{Commands, Component, World};
+    /// # use bevy::ecs::world::CommandQueue;
+    /// use bevy_djqf::{disjoint, DisjointEntityCommandsExt};
+    ///
+    /// #[derive(Component, Debug, Default)]
+    /// struct Active;
+    ///
+    /// #[derive(Component, Debug, Default)]
+    /// struct Inactive;
+    ///
+    /// disjoint!(Active, Inactive);
+    ///
+    /// let mut world = World::new();
+    /// let entity = world.spawn(Active).id();
+    /// let untagged = world.spawn_empty().id();
+    ///
+    /// let mut queue = CommandQueue::default();
+    /// Commands::new(&mut queue, &world).entity(entity).toggle_variant::<Active, Inactive>();
+    /// Commands::new(&mut queue, &world).entity(untagged).toggle_variant::<Active, Inactive>();
+    /// queue.apply(&mut world);
+    ///
+    /// assert!(!world.entity(entity).contains::<Active>());
+    /// assert!(world.entity(entity).contains::<Inactive>());
+    /// assert!(world.entity(untagged).contains::<Active>());
+    ///
+    /// // toggling twice returns to the original "variant"
+    /// Commands::new(&mut queue, &world).entity(entity).toggle_variant::<Active, Inactive>();
+    /// queue.apply(&mut world);
+    ///
+    /// assert!(world.entity(entity).contains::<Active>());
+    /// assert!(!world.entity(entity).contains::<Inactive>());
+    /// ```
+    ///
+    /// "Variants" from different groups are rejected:
+    /// ```compile_fail
+    /// # use bevy::prelude::{Commands, Component, World};
+    /// # use bevy::ecs::world::CommandQueue;
+    /// use bevy_djqf::{disjoint, DisjointEntityCommandsExt};
+    ///
+    /// #[derive(Component, Debug, Default)]
+    /// struct A;
+    ///
+    /// #[derive(Component, Debug, Default)]
+    /// struct B;
+    ///
+    /// #[derive(Component, Debug, Default)]
+    /// struct X;
+    ///
+    /// #[derive(Component, Debug, Default)]
+    /// struct Y;
+    ///
+    /// disjoint!(A, B);
+    /// disjoint!(X, Y);
+    ///
+    /// let mut world = World::new();
+    /// let entity = world.spawn(A).id();
+    /// let mut queue = CommandQueue::default();
+    /// Commands::new(&mut queue, &world).entity(entity).toggle_variant::<A, X>();
+    /// ```
+    fn toggle_variant<A, B>(&mut self) -> &mut Self
+    where
+        A: Disjoint + Component + Default,
+        B: GroupMember<A::Group> + Component + Default;
+
+    /// Remove every "variant" of the group `G` from this entity, so that it matches [`None`](Disjoint::None).
+    ///
+    /// `G` is any [`DisjointGroup`], e.g. the first type provided to the [`disjoint!`](crate::disjoint) macro,
+    /// or `<A as Disjoint>::Group` for any member `A`. Removing a "variant" the entity doesn't have is not an error.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::{Commands, Component, World};
+    /// # use bevy::ecs::world::CommandQueue;
+    /// use bevy_djqf::{disjoint, Disjoint, DisjointEntityCommandsExt};
+    ///
+    /// #[derive(Component, Debug, Default)]
+    /// struct A;
+    ///
+    /// #[derive(Component, Debug, Default)]
+    /// struct B;
+    ///
+    /// disjoint!(A, B);
+    ///
+    /// let mut world = World::new();
+    /// let entity = world.spawn(B).id();
+    ///
+    /// let mut queue = CommandQueue::default();
+    /// Commands::new(&mut queue, &world)
+    ///     .entity(entity)
+    ///     .clear_variant::<<B as Disjoint>::Group>();
+    /// queue.apply(&mut world);
+    ///
+    /// let mut none = world.query_filtered::<(), <A as Disjoint>::None>();
+    /// assert!(none.get(&world, entity).is_ok());
+    /// ```
+    fn clear_variant<G: DisjointGroup>(&mut self) -> &mut Self;
+
+    /// Insert the "variant" `V` on this entity only if it has no "variant" of the same "enum",
+    /// so that it matches [`None`](Disjoint::None), and otherwise keep the "variant" it has.
+    ///
+    /// This is checked when the command is applied, e.g. to give an entity a "variant" without overwriting
+    /// one inserted earlier. For the `#[default]` "variant" of a group `G`, use `ensure_variant::<G::Default>()`,
+    /// see [`DefaultVariant`].
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::{Commands, Component, World};
+    /// # use bevy::ecs::world::CommandQueue;
+    /// use bevy_djqf::{disjoint, DisjointEntityCommandsExt};
+    ///
+    /// #[derive(Component, Debug, Default)]
+    /// struct Idle;
+    ///
+    /// #[derive(Component, Debug, Default)]
+    /// struct Running;
+    ///
+    /// disjoint!(Idle, Running);
+    ///
+    /// let mut world = World::new();
+    /// let untagged = world.spawn_empty().id();
+    /// let running = world.spawn(Running).id();
+    ///
+    /// let mut queue = CommandQueue::default();
+    /// let mut commands = Commands::new(&mut queue, &world);
+    /// commands.entity(untagged).ensure_variant::<Idle>();
+    /// commands.entity(running).ensure_variant::<Idle>();
+    /// queue.apply(&mut world);
+    ///
+    /// assert!(world.entity(untagged).contains::<Idle>());
+    /// assert!(!world.entity(running).contains::<Idle>());
+    /// assert!(world.entity(running).contains::<Running>());
+    /// ```
+    fn ensure_variant<V: Disjoint + Component + Default>(&mut self) -> &mut Self;
+
+    /// Insert the "variant" for the enum `value` on this entity, and remove every other "variant" of the same "enum".
+    ///
+    /// This is [`set_variant`](Self::set_variant) for a [`DisjointEnum`] value that is only known at runtime.
+    /// See the [`Disjoint`](derive@crate::Disjoint) derive macro for an example.
+    fn set_variant_value<E: DisjointEnum + Send + 'static>(&mut self, value: E) -> &mut Self
+    where
+        E::Group: DefaultMembers;
+
+    /// Insert the sub-"variant" `V` and its [`Parent`](Subvariant::Parent) on this entity,
+    /// and remove every other "variant" of both of their "enums".
+    ///
+    /// This is [`set_variant`](Self::set_variant) for both levels of a [`disjoint_tree!`](crate::disjoint_tree),
+    /// see there for an example.
+    fn set_subvariant<V>(&mut self) -> &mut Self
+    where
+        V: Subvariant + Component + Default,
+        V::Parent: Component + Default;
+
+    /// Insert the "variant" of the group `G` at `index` on this entity, and remove every other "variant",
+    /// e.g. for an index loaded from a config file.
+    ///
+    /// This is [`set_variant`](Self::set_variant) for an [`INDEX`](Disjoint::INDEX) that is only known at runtime.
+    ///
+    /// # Errors
+    /// Returns [`OutOfRange`] without changing the entity if there is no "variant" at `index`.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::{Commands, Component, World};
+    /// # use bevy::ecs::world::CommandQueue;
+    /// use bevy_djqf::{disjoint, DisjointEntityCommandsExt, OutOfRange};
+    ///
+    /// #[derive(Component, Debug, Default)]
+    /// struct A;
+    ///
+    /// #[derive(Component, Debug, Default)]
+    /// struct B;
+    ///
+    /// disjoint!(A, B);
+    ///
+    /// let mut world = World::new();
+    /// let entity = world.spawn(A).id();
+    ///
+    /// let mut queue = CommandQueue::default();
+    /// let mut commands = Commands::new(&mut queue, &world);
+    /// assert!(commands.entity(entity).set_variant_by_index::<A>(1).is_ok());
+    /// assert_eq!(
+    ///     commands.entity(entity).set_variant_by_index::<A>(2).err(),
+    ///     Some(OutOfRange { index: 2, count: 2 }),
+    /// );
+    /// queue.apply(&mut world);
+    ///
+    /// assert!(!world.entity(entity).contains::<A>());
+    /// assert!(world.entity(entity).contains::<B>());
+    /// ```
+    fn set_variant_by_index<G: DefaultMembers>(
+        &mut self,
+        index: usize,
+    ) -> Result<&mut Self, OutOfRange>;
+
+    /// Insert the "variant" of the group `G` whose [`NAME`](Disjoint::NAME) is `name` on this entity,
+    /// and remove every other "variant", e.g. for a debug console or an editor command.
+    ///
+    /// The name must match exactly, including case, and is the type as it was provided to the
+    /// [`disjoint!`](crate::disjoint) macro, e.g. `"enemies::Goblin"` for `disjoint!(enemies::Goblin, ...)`.
+    ///
+    /// # Errors
+    /// Returns [`UnknownVariant`] without changing the entity if no "variant" has this name.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::{Commands, Component, World};
+    /// # use bevy::ecs::world::CommandQueue;
+    /// use bevy_djqf::{disjoint, DisjointEntityCommandsExt};
+    ///
+    /// #[derive(Component, Debug, Default)]
+    /// struct Player;
+    ///
+    /// #[derive(Component, Debug, Default)]
+    /// struct Enemy;
+    ///
+    /// disjoint!(Player, Enemy);
+    ///
+    /// let mut world = World::new();
+    /// let entity = world.spawn(Player).id();
+    ///
+    /// let mut queue = CommandQueue::default();
+    /// let mut commands = Commands::new(&mut queue, &world);
+    /// assert!(commands.entity(entity).set_variant_by_name::<Player>("Enemy").is_ok());
+    ///
+    /// let error = commands.entity(entity).set_variant_by_name::<Player>("enemy").err().unwrap();
+    /// assert_eq!(error.name, "enemy");
+    /// assert_eq!(error.to_string(), r#"there is no "variant" named "enemy", expected one of ["Player", "Enemy"]"#);
+    /// queue.apply(&mut world);
+    ///
+    /// assert!(!world.entity(entity).contains::<Player>());
+    /// assert!(world.entity(entity).contains::<Enemy>());
+    /// ```
+    fn set_variant_by_name<G: DefaultMembers>(
+        &mut self,
+        name: &str,
+    ) -> Result<&mut Self, UnknownVariant>;
+}
+
+impl DisjointEntityCommandsExt for EntityCommands<'_> {
+    fn set_variant<V: Disjoint + Component + Default>(&mut self) -> &mut Self {
+        self.add(|mut entity: EntityWorldMut| {
+            set_variant::<V>(&mut entity);
+        })
+    }
+
+    fn insert_variant<V: Disjoint + Component>(&mut self, value: V) -> &mut Self {
+        self.add(move |mut entity: EntityWorldMut| {
+            insert_variant(&mut entity, value);
+        })
+    }
+
+    fn toggle_variant<A, B>(&mut self) -> &mut Self
+    where
+        A: Disjoint + Component + Default,
+        B: GroupMember<A::Group> + Component + Default,
+    {
+        self.add(|mut entity: EntityWorldMut| {
+            if entity.contains::<A>() {
+                set_variant::<B>(&mut entity);
+            } else {
+                set_variant::<A>(&mut entity);
+            }
+        })
+    }
+
+    fn clear_variant<G: DisjointGroup>(&mut self) -> &mut Self {
+        self.add(|mut entity: EntityWorldMut| {
+            remove_others::<G>(&mut entity, None);
+        })
+    }
+
+    fn ensure_variant<V: Disjoint + Component + Default>(&mut self) -> &mut Self {
+        self.add(|mut entity: EntityWorldMut| {
+            ensure_variant::<V>(&mut entity);
+        })
+    }
+
+    fn set_variant_value<E: DisjointEnum + Send + 'static>(&mut self, value: E) -> &mut Self
+    where
+        E::Group: DefaultMembers,
+    {
+        self.add(move |mut entity: EntityWorldMut| {
+            set_variant_index::<E::Group>(&mut entity, Some(value.index()));
+        })
+    }
+
+    fn set_subvariant<V>(&mut self) -> &mut Self
+    where
+        V: Subvariant + Component + Default,
+        V::Parent: Component + Default,
+    {
+        self.add(|mut entity: EntityWorldMut| {
+            set_variant::<V::Parent>(&mut entity);
+            set_variant::<V>(&mut entity);
+        })
+    }
+
+    fn set_variant_by_index<G: DefaultMembers>(
+        &mut self,
+        index: usize,
+    ) -> Result<&mut Self, OutOfRange> {
+        let count = G::COUNT;
+        if !has_member::<G>(index) {
+            return Err(OutOfRange { index, count });
+        }
+        Ok(self.add(move |mut entity: EntityWorldMut| {
+            set_variant_index::<G>(&mut entity, Some(index));
+        }))
+    }
+
+    fn set_variant_by_name<G: DefaultMembers>(
+        &mut self,
+        name: &str,
+    ) -> Result<&mut Self, UnknownVariant> {
+        // an index without a "variant" has an empty name, which must not match
+        let Some(index) = G::NAMES
+            .iter()
+            .position(|member| !member.is_empty() && *member == name)
+        else {
+            return Err(UnknownVariant {
+                name: name.to_owned(),
+                expected: G::NAMES,
+            });
+        };
+        Ok(self.add(move |mut entity: EntityWorldMut| {
+            set_variant_index::<G>(&mut entity, Some(index));
+        }))
+    }
+}
+
+/// Extension methods on [`EntityWorldMut`] for changing the "variant" of the entity immediately,
+/// e.g. in exclusive systems and tests, rather than when commands are applied.
+pub trait DisjointEntityWorldMutExt {
+    /// Insert the "variant" `V` on this entity, and remove every other "variant" of the same "enum", immediately.
+    ///
+    /// Removing a "variant" the entity doesn't have is not an error.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::{Component, World};
+    /// use bevy_djqf::{disjoint, DisjointEntityWorldMutExt};
+    ///
+    /// #[derive(Component, Debug, Default)]
+    /// struct A;
+    ///
+    /// #[derive(Component, Debug, Default)]
+    /// struct B;
+    ///
+    /// #[derive(Component, Debug, Default)]
+    /// struct C;
+    ///
+    /// disjoint!(A, B, C);
+    ///
+    /// let mut world = World::new();
+    /// let mut entity = world.spawn((A, C));
+    /// entity.set_variant::<B>();
+    ///
+    /// assert!(!entity.contains::<A>());
+    /// assert!(entity.contains::<B>());
+    /// assert!(!entity.contains::<C>());
+    /// ```
+    fn set_variant<V: Disjoint + Component + Default>(&mut self) -> &mut Self;
+
+    /// Insert `value` as the "variant" of this entity, and remove every other "variant" of the same "enum", immediately.
+    ///
+    /// This is [`set_variant`](Self::set_variant) for markers that don't implement [`Default`], e.g. markers with fields.
+    fn insert_variant<V: Disjoint + Component>(&mut self, value: V) -> &mut Self;
+
+    /// Remove every "variant" of the group `G` from this entity, immediately, so that it matches [`None`](Disjoint::None).
+    ///
+    /// Removing a "variant" the entity doesn't have is not an error.
+    fn clear_variant<G: DisjointGroup>(&mut self) -> &mut Self;
+
+    /// Insert the "variant" `V` on this entity only if it has no "variant" of the same "enum", immediately,
+    /// and otherwise keep the "variant" it has.
+    fn ensure_variant<V: Disjoint + Component + Default>(&mut self) -> &mut Self;
+}
+
+impl DisjointEntityWorldMutExt for EntityWorldMut<'_> {
+    fn set_variant<V: Disjoint + Component + Default>(&mut self) -> &mut Self {
+        set_variant::<V>(self);
+        self
+    }
+
+    fn insert_variant<V: Disjoint + Component>(&mut self, value: V) -> &mut Self {
+        insert_variant(self, value);
+        self
+    }
+
+    fn clear_variant<G: DisjointGroup>(&mut self) -> &mut Self {
+        remove_others::<G>(self, None);
+        self
+    }
+
+    fn ensure_variant<V: Disjoint + Component + Default>(&mut self) -> &mut Self {
+        ensure_variant::<V>(self);
+        self
+    }
+}
+
+/// The error when there is no "variant" at an index, returned by [`DisjointEntityCommandsExt::set_variant_by_index`]
+/// and [`DisjointCommandsExt::spawn_variants_from_fn`], and by the `TryFrom<u8>` (or `TryFrom<u16>`) implementation of enums with `#[derive(Disjoint)]`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OutOfRange {
+    /// The index that was provided.
+    pub index: usize,
+    /// The [`COUNT`](DisjointGroup::COUNT) of the group, so the largest valid index is one less.
+    /// With explicit indices, there can also be smaller indices without a "variant".
+    pub count: usize,
+}
+
+impl std::fmt::Display for OutOfRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "there is no \"variant\" at index {}, of the {} indices of the group",
+            self.index, self.count
+        )
+    }
+}
+
+impl std::error::Error for OutOfRange {}
+
+/// The error when no "variant" has a name, returned by [`DisjointEntityCommandsExt::set_variant_by_name`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnknownVariant {
+    /// The name that was provided.
+    pub name: String,
+    /// The [`NAMES`](DisjointGroup::NAMES) of the group.
+    pub expected: &'static [&'static str],
+}
+
+impl std::fmt::Display for UnknownVariant {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "there is no \"variant\" named {:?}, expected one of {:?}",
+            self.name, self.expected
+        )
+    }
+}
+
+impl std::error::Error for UnknownVariant {}
+
+fn set_variant<V: Disjoint + Component + Default>(entity: &mut EntityWorldMut) {
+    insert_variant(entity, V::default());
+}
+
+fn insert_variant<V: Disjoint + Component>(entity: &mut EntityWorldMut, value: V) {
+    remove_others::<V::Group>(entity, Some(V::INDEX));
+    entity.insert(value);
+}
+
+fn ensure_variant<V: Disjoint + Component + Default>(entity: &mut EntityWorldMut) {
+    if variant_of::<V::Group>(&EntityRef::from(&*entity)).is_none() {
+        entity.insert(V::default());
+    }
+}
+
+/// Set the "variant" of `G` at `index` (removing every other "variant"), or remove every "variant" if `None`.
+pub(crate) fn set_variant_index<G: DefaultMembers>(
+    entity: &mut EntityWorldMut,
+    index: Option<usize>,
+) {
+    remove_others::<G>(entity, index);
+    if let Some(index) = index {
+        G::insert(entity, index);
+    }
+}
+
+/// A group whose "variants" can all be created with [`Default`], so that they can be inserted by [`INDEX`](Disjoint::INDEX).
+///
+/// Implemented by the [`disjoint!`](crate::disjoint) macro for every group whose "variants" all implement [`Default`],
+/// whatever its size.
+pub trait DefaultMembers: DisjointGroup {
+    /// Insert the default value of the member at `index` on the entity.
+    ///
+    /// Returns `false` if there is no member at `index`.
+    fn insert(entity: &mut EntityWorldMut, index: usize) -> bool;
+}
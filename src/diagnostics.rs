@@ -0,0 +1,92 @@
+use std::marker::PhantomData;
+
+use bevy_app::{App, Plugin, Update};
+use bevy_diagnostic::{Diagnostic, DiagnosticPath, Diagnostics, RegisterDiagnostic};
+use bevy_ecs::{archetype::Archetypes, component::Components};
+
+use crate::{
+    histogram::count_only,
+    visitors::{group_name, has_member},
+    DisjointGroup,
+};
+
+/// Records how many entities have only each "variant" of the group `G`, as one [`Diagnostic`] per "variant",
+/// e.g. for the `LogDiagnosticsPlugin`. Requires the `diagnostics` feature.
+///
+/// `G` is any [`DisjointGroup`], e.g. the first type provided to the [`disjoint!`](crate::disjoint) macro.
+/// Each count is the number of entities matching [`Only`](crate::Disjoint::Only) for that "variant",
+/// measured in the [`Update`] schedule, at the path from [`path`](Self::path).
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::{App, Component};
+/// # use bevy::diagnostic::DiagnosticsStore;
+/// use bevy_djqf::{disjoint, Disjoint, DisjointDiagnosticsPlugin};
+///
+/// #[derive(Component, Debug, Default)]
+/// struct A;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct B;
+///
+/// disjoint!(A, B);
+///
+/// let mut app = App::new();
+/// app.add_plugins(DisjointDiagnosticsPlugin::<A>::default());
+/// app.world_mut().spawn(A);
+/// app.world_mut().spawn(B);
+/// app.world_mut().spawn(B);
+/// app.update();
+///
+/// let store = app.world().resource::<DiagnosticsStore>();
+/// let count = |index| store.get(&DisjointDiagnosticsPlugin::<A>::path(index))?.value();
+/// assert_eq!(count(<A as Disjoint>::INDEX), Some(1.0));
+/// assert_eq!(count(<B as Disjoint>::INDEX), Some(2.0));
+/// assert_eq!(DisjointDiagnosticsPlugin::<A>::path(1).as_str(), "disjoint/A/B");
+/// ```
+pub struct DisjointDiagnosticsPlugin<G>(PhantomData<fn() -> G>);
+
+impl<G> Default for DisjointDiagnosticsPlugin<G> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<G: DisjointGroup> DisjointDiagnosticsPlugin<G> {
+    /// The path of the diagnostic for the "variant" at [`INDEX`](crate::Disjoint::INDEX) `index`,
+    /// `disjoint/{group}/{variant}`, where the group is named after its first member.
+    ///
+    /// # Panics
+    /// If there is no "variant" at `index`.
+    pub fn path(index: usize) -> DiagnosticPath {
+        assert!(
+            has_member::<G>(index),
+            "there is no \"variant\" at index {index}"
+        );
+        DiagnosticPath::from_components(["disjoint", group_name::<G>(), G::NAMES[index]])
+    }
+}
+
+impl<G: DisjointGroup + 'static> Plugin for DisjointDiagnosticsPlugin<G> {
+    fn build(&self, app: &mut App) {
+        // an index without a "variant" has no diagnostic
+        let paths: Vec<(usize, DiagnosticPath)> = (0..G::COUNT)
+            .filter(|&index| has_member::<G>(index))
+            .map(|index| (index, Self::path(index)))
+            .collect();
+        for (_, path) in &paths {
+            app.register_diagnostic(Diagnostic::new(path.clone()));
+        }
+        app.add_systems(
+            Update,
+            move |mut diagnostics: Diagnostics,
+                  archetypes: &Archetypes,
+                  components: &Components| {
+                let counts = count_only::<G>(archetypes, components);
+                for (index, path) in &paths {
+                    diagnostics.add_measurement(path, || counts[*index] as f64);
+                }
+            },
+        );
+    }
+}
@@ -0,0 +1,196 @@
+use bevy_ecs::{
+    component::{Component, ComponentId},
+    query::{QueryBuilder, QueryData, QueryFilter},
+    world::World,
+};
+
+use crate::{visitors::init_member_ids, DisjointGroup};
+
+/// Never inserted on any entity, so that a query filtered with it matches nothing.
+#[derive(Component)]
+struct Nothing;
+
+/// Filter the query so that it matches nothing, e.g. for [`Any`](crate::Disjoint::Any) of a group without "variants".
+fn nothing<D: QueryData, F: QueryFilter>(builder: &mut QueryBuilder<D, F>) {
+    builder.with::<Nothing>();
+}
+
+/// A group of disjoint "variants" given by their [`ComponentId`]s, e.g. for data-driven games
+/// where the "variants" aren't known at compile time.
+///
+/// This adds the same filters as the [`Disjoint`](crate::Disjoint) associated types to a [`QueryBuilder`],
+/// e.g. for a `QueryBuilder::<FilteredEntityRef>` (or `QueryBuilder::<Entity>`), with [`only`](Self::only),
+/// [`other`](Self::other), [`any`](Self::any) and [`none`](Self::none). [`DisjointRegistry`](crate::DisjointRegistry) is built on this.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::{Component, Entity, World};
+/// # use bevy::ecs::query::QueryBuilder;
+/// use bevy_djqf::DynamicDisjoint;
+///
+/// #[derive(Component)]
+/// struct A;
+///
+/// #[derive(Component)]
+/// struct B;
+///
+/// let mut world = World::new();
+/// let a = world.init_component::<A>();
+/// let b = world.init_component::<B>();
+/// let group = DynamicDisjoint::new([a, b]);
+///
+/// let only_a = world.spawn(A).id();
+/// world.spawn((A, B));
+/// let only_b = world.spawn(B).id();
+///
+/// let mut query = QueryBuilder::<Entity>::new(&mut world);
+/// group.only(&mut query, a);
+/// let mut query = query.build();
+/// assert_eq!(query.iter(&world).collect::<Vec<_>>(), [only_a]);
+///
+/// let mut query = QueryBuilder::<Entity>::new(&mut world);
+/// group.other(&mut query, a);
+/// let mut query = query.build();
+/// assert_eq!(query.iter(&world).collect::<Vec<_>>(), [only_b]);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DynamicDisjoint {
+    ids: Vec<ComponentId>,
+}
+
+impl DynamicDisjoint {
+    /// Create the group from the [`ComponentId`] of each "variant", in order. Repeated ids are only added once.
+    pub fn new(ids: impl IntoIterator<Item = ComponentId>) -> Self {
+        let mut group = Self::default();
+        for id in ids {
+            group.insert(id);
+        }
+        group
+    }
+
+    /// Create the group from the members of the static group `G`, registering them if needed,
+    /// so that [`INDEX`](crate::Disjoint::INDEX) is the same in both.
+    ///
+    /// An index without a member, from explicit indices with gaps, is given a [`ComponentId`] that no entity has.
+    pub fn from_group<G: DisjointGroup>(world: &mut World) -> Self {
+        let nothing = world.init_component::<Nothing>();
+        // not `new`, which would only add the id of `Nothing` once
+        Self {
+            ids: init_member_ids::<G>(world)
+                .into_iter()
+                .map(|id| id.unwrap_or(nothing))
+                .collect(),
+        }
+    }
+
+    /// Add a "variant", unless it is already a member.
+    ///
+    /// Returns its index, which is the order it was added in, starting from `0`.
+    pub fn insert(&mut self, id: ComponentId) -> usize {
+        if let Some(index) = self.index(id) {
+            return index;
+        }
+        self.ids.push(id);
+        self.ids.len() - 1
+    }
+
+    /// The [`ComponentId`] of every "variant", in order.
+    pub fn ids(&self) -> &[ComponentId] {
+        &self.ids
+    }
+
+    /// The number of "variants".
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    /// Whether there are no "variants" yet.
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    /// The index of the "variant" with this [`ComponentId`], if it is a member.
+    pub fn index(&self, id: ComponentId) -> Option<usize> {
+        self.ids.iter().position(|member| *member == id)
+    }
+
+    /// Filter the query to entities with the "variant" `id`, and no other "variant", like [`Only`](crate::Disjoint::Only).
+    pub fn only<D: QueryData, F: QueryFilter>(
+        &self,
+        builder: &mut QueryBuilder<D, F>,
+        id: ComponentId,
+    ) {
+        builder.with_id(id);
+        for &member in &self.ids {
+            if member != id {
+                builder.without_id(member);
+            }
+        }
+    }
+
+    /// Filter the query to entities without the "variant" `id`, but with another "variant", like [`Other`](crate::Disjoint::Other).
+    pub fn other<D: QueryData, F: QueryFilter>(
+        &self,
+        builder: &mut QueryBuilder<D, F>,
+        id: ComponentId,
+    ) {
+        builder.without_id(id);
+        if self.ids.iter().all(|member| *member == id) {
+            // there is no other "variant"
+            nothing(builder);
+            return;
+        }
+        builder.or(|builder| {
+            for &member in &self.ids {
+                if member != id {
+                    builder.with_id(member);
+                }
+            }
+        });
+    }
+
+    /// Filter the query to entities with any "variant", like [`Any`](crate::Disjoint::Any).
+    ///
+    /// While there are no "variants", nothing matches.
+    pub fn any<D: QueryData, F: QueryFilter>(&self, builder: &mut QueryBuilder<D, F>) {
+        if self.ids.is_empty() {
+            nothing(builder);
+            return;
+        }
+        builder.or(|builder| {
+            for &member in &self.ids {
+                builder.with_id(member);
+            }
+        });
+    }
+
+    /// Filter the query to entities with any of the "variants" at these indices, e.g. for a subset that is only known at runtime.
+    /// Unlike [`disjoint_subset!`](crate::disjoint_subset), this doesn't exclude the other "variants".
+    /// Indices without a "variant" are ignored, so nothing matches if none of the indices has a "variant".
+    pub fn any_of<D: QueryData, F: QueryFilter>(
+        &self,
+        builder: &mut QueryBuilder<D, F>,
+        indices: impl IntoIterator<Item = usize>,
+    ) {
+        let ids = indices
+            .into_iter()
+            .filter_map(|index| self.ids.get(index).copied())
+            .collect::<Vec<_>>();
+        if ids.is_empty() {
+            nothing(builder);
+            return;
+        }
+        builder.or(|builder| {
+            for &id in &ids {
+                builder.with_id(id);
+            }
+        });
+    }
+
+    /// Filter the query to entities with no "variant", like [`None`](crate::Disjoint::None).
+    pub fn none<D: QueryData, F: QueryFilter>(&self, builder: &mut QueryBuilder<D, F>) {
+        for &member in &self.ids {
+            builder.without_id(member);
+        }
+    }
+}
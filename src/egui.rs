@@ -0,0 +1,44 @@
+use bevy_ecs::{entity::Entity, system::Commands};
+use bevy_utils::tracing::warn;
+
+use crate::{DefaultMembers, DisjointEntityCommandsExt};
+
+/// Show a dropdown of the [`NAMES`](crate::DisjointGroup::NAMES) of the group `G` for `entity`, e.g. in an editor or inspector,
+/// and set the "variant" the user picks with [`set_variant_by_index`](DisjointEntityCommandsExt::set_variant_by_index).
+/// Requires the `egui` feature.
+///
+/// `current` is the [`INDEX`](crate::Disjoint::INDEX) of the "variant" the entity has, e.g. from [`VariantOf`](crate::VariantOf),
+/// which is shown as selected, or `None` if it has no "variant". The dropdown is identified by the group and the entity,
+/// so several can be shown at once.
+///
+/// See `examples/variant_picker.rs`.
+pub fn variant_picker<G: DefaultMembers>(
+    ui: &mut egui::Ui,
+    entity: Entity,
+    current: Option<usize>,
+    commands: &mut Commands,
+) -> egui::Response {
+    let mut picked = current;
+    let selected = current
+        .and_then(|index| G::NAMES.get(index).copied())
+        .unwrap_or("None");
+    let response = egui::ComboBox::from_id_source((std::any::type_name::<G>(), entity))
+        .selected_text(selected)
+        .show_ui(ui, |ui| {
+            // an index without a "variant" has an empty name
+            for (index, name) in G::NAMES
+                .iter()
+                .enumerate()
+                .filter(|(_, name)| !name.is_empty())
+            {
+                ui.selectable_value(&mut picked, Some(index), *name);
+            }
+        })
+        .response;
+    if let Some(index) = picked.filter(|_| picked != current) {
+        if let Err(error) = commands.entity(entity).set_variant_by_index::<G>(index) {
+            warn!("Couldn't set the \"variant\" picked for entity {entity:?}: {error}");
+        }
+    }
+    response
+}
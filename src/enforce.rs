@@ -0,0 +1,270 @@
+use std::{marker::PhantomData, time::Duration};
+
+use bevy_app::{App, Last, Plugin, PostStartup};
+use bevy_ecs::{
+    archetype::Archetypes,
+    component::{ComponentId, Components},
+    schedule::IntoSystemConfigs,
+    system::{Local, SystemChangeTick},
+    world::World,
+};
+use bevy_utils::{tracing::warn, Instant};
+
+use crate::{visitors::member_ids, DisjointGroup};
+
+/// What to do when an entity is found to have more than one "variant" of the same "enum".
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OnViolation {
+    /// Panic, naming the entity and the conflicting "variants".
+    #[default]
+    Panic,
+    /// Log a warning, naming the entity and the conflicting "variants".
+    Warn,
+}
+
+/// Checks that no entity has more than one "variant" of the group `G`, in debug builds.
+///
+/// `G` is any [`DisjointGroup`], e.g. the first type provided to the [`disjoint!`](crate::disjoint) macro,
+/// or `<A as Disjoint>::Group` for any member `A`.
+///
+/// The check runs in the [`Last`] schedule. In release builds this plugin does nothing.
+///
+/// If only one of the conflicting "variants" was added since the last check, i.e. usually in the same frame,
+/// the report names it, e.g. "Entity ... gained `Enemy` while already having `Player`",
+/// to help find the system that added it.
+///
+/// # Example
+/// ```should_panic
+/// # use bevy::prelude::{App, Component};
+/// use bevy_djqf::{disjoint, DisjointEnforcePlugin};
+///
+/// #[derive(Component, Debug, Default)]
+/// struct A;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct B;
+///
+/// disjoint!(A, B);
+///
+/// let mut app = App::new();
+/// app.add_plugins(DisjointEnforcePlugin::<A>::default());
+/// app.world_mut().spawn((A, B));
+///
+/// // panics: the entity has both `A` and `B`
+/// app.update();
+/// ```
+///
+/// To only log a warning instead:
+/// ```
+/// # use bevy::prelude::{App, Component};
+/// use bevy_djqf::{disjoint, DisjointEnforcePlugin, OnViolation};
+///
+/// #[derive(Component, Debug, Default)]
+/// struct A;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct B;
+///
+/// disjoint!(A, B);
+///
+/// let mut app = App::new();
+/// app.add_plugins(DisjointEnforcePlugin::<A>::new(OnViolation::Warn));
+/// app.world_mut().spawn((A, B));
+/// app.update();
+/// ```
+pub struct DisjointEnforcePlugin<G> {
+    /// What to do when a violation is found. Defaults to [`OnViolation::Panic`].
+    pub on_violation: OnViolation,
+    _group: PhantomData<fn() -> G>,
+}
+
+impl<G> DisjointEnforcePlugin<G> {
+    /// Create the plugin with the given behaviour on violations.
+    pub fn new(on_violation: OnViolation) -> Self {
+        Self {
+            on_violation,
+            _group: PhantomData,
+        }
+    }
+}
+
+impl<G> Default for DisjointEnforcePlugin<G> {
+    fn default() -> Self {
+        Self::new(OnViolation::default())
+    }
+}
+
+impl<G: DisjointGroup + 'static> Plugin for DisjointEnforcePlugin<G> {
+    fn build(&self, app: &mut App) {
+        if cfg!(debug_assertions) {
+            let on_violation = self.on_violation;
+            app.add_systems(Last, move |world: &World, ticks: SystemChangeTick| {
+                enforce_disjoint::<G>(world, ticks, on_violation);
+            });
+        }
+    }
+}
+
+fn enforce_disjoint<G: DisjointGroup>(
+    world: &World,
+    ticks: SystemChangeTick,
+    on_violation: OnViolation,
+) {
+    let members = member_ids::<G>(world.components());
+
+    for archetype in world.archetypes().iter() {
+        // entities in the same archetype have the same components, so check the archetype once
+        let present: Vec<(&'static str, ComponentId)> = members
+            .iter()
+            .filter_map(|(name, id)| {
+                id.filter(|id| archetype.contains(*id))
+                    .map(|id| (*name, id))
+            })
+            .collect();
+        if present.len() < 2 {
+            continue;
+        }
+        for entity in archetype.entities() {
+            let entity = world.entity(entity.id());
+            // whether each "variant" was added since the last check, to tell which one caused the violation
+            let added: Vec<bool> = present
+                .iter()
+                .map(|(_, id)| {
+                    entity
+                        .get_change_ticks_by_id(*id)
+                        .is_some_and(|t| t.is_added(ticks.last_run(), ticks.this_run()))
+                })
+                .collect();
+            let entity = entity.id();
+            for (i, (first, _)) in present.iter().enumerate() {
+                for (j, (second, _)) in present.iter().enumerate().skip(i + 1) {
+                    let message = match (added[i], added[j]) {
+                        (false, true) => format!(
+                            "Entity {entity:?} gained `{second}` while already having `{first}`, which are disjoint"
+                        ),
+                        (true, false) => format!(
+                            "Entity {entity:?} gained `{first}` while already having `{second}`, which are disjoint"
+                        ),
+                        _ => format!(
+                            "Entity {entity:?} has both `{first}` and `{second}`, which are disjoint"
+                        ),
+                    };
+                    match on_violation {
+                        OnViolation::Panic => panic!("{message}"),
+                        OnViolation::Warn => warn!("{message}"),
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Logs how many entities have more than one "variant" of the group `G`, for each conflicting pair of "variants".
+///
+/// Unlike [`DisjointEnforcePlugin`], this runs in all builds, and only logs a summary.
+/// The check runs once in [`PostStartup`], and optionally again every [`interval`](Self::interval) in the [`Last`] schedule.
+///
+/// The check itself is the [`check_disjoint`] system, which can also be added to any schedule directly.
+///
+/// # Example
+/// ```
+/// # use std::time::Duration;
+/// # use bevy::prelude::{App, Component};
+/// use bevy_djqf::{disjoint, DisjointCheckPlugin};
+///
+/// #[derive(Component, Debug, Default)]
+/// struct A;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct B;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct C;
+///
+/// disjoint!(A, B, C);
+///
+/// let mut app = App::new();
+/// app.add_plugins(DisjointCheckPlugin::<A>::every(Duration::from_secs(5)));
+/// app.world_mut().spawn((A, B));
+/// app.world_mut().spawn((A, B, C));
+///
+/// // warns that 2 entities have both `A` and `B`, 1 has both `A` and `C`, and 1 has both `B` and `C`
+/// app.update();
+/// ```
+pub struct DisjointCheckPlugin<G> {
+    /// How often to check again after startup. Defaults to `None`, which only checks at startup.
+    pub interval: Option<Duration>,
+    _group: PhantomData<fn() -> G>,
+}
+
+impl<G> DisjointCheckPlugin<G> {
+    /// Create the plugin, checking at startup and then every `interval`.
+    pub fn every(interval: Duration) -> Self {
+        Self {
+            interval: Some(interval),
+            _group: PhantomData,
+        }
+    }
+}
+
+impl<G> Default for DisjointCheckPlugin<G> {
+    fn default() -> Self {
+        Self {
+            interval: None,
+            _group: PhantomData,
+        }
+    }
+}
+
+impl<G: DisjointGroup + 'static> Plugin for DisjointCheckPlugin<G> {
+    fn build(&self, app: &mut App) {
+        app.add_systems(PostStartup, check_disjoint::<G>);
+        if let Some(interval) = self.interval {
+            app.add_systems(
+                Last,
+                check_disjoint::<G>.run_if(move |mut last: Local<Option<Instant>>| {
+                    let now = Instant::now();
+                    // the startup check counts as the first one
+                    let last = last.get_or_insert(now);
+                    if now.duration_since(*last) < interval {
+                        return false;
+                    }
+                    *last = now;
+                    true
+                }),
+            );
+        }
+    }
+}
+
+/// A system that logs a warning with the number of entities that have both of each pair of "variants" of the group `G`.
+///
+/// Nothing is logged if no entity has more than one "variant". See [`DisjointCheckPlugin`].
+pub fn check_disjoint<G: DisjointGroup>(archetypes: &Archetypes, components: &Components) {
+    let members = member_ids::<G>(components);
+    let mut counts = vec![0; members.len() * members.len()];
+
+    for archetype in archetypes.iter() {
+        // entities in the same archetype have the same components, so count the archetype once
+        let present: Vec<usize> = members
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, id))| id.is_some_and(|id| archetype.contains(id)))
+            .map(|(index, _)| index)
+            .collect();
+        for (i, first) in present.iter().enumerate() {
+            for second in &present[i + 1..] {
+                counts[first * members.len() + second] += archetype.len();
+            }
+        }
+    }
+
+    for (first, (first_name, _)) in members.iter().enumerate() {
+        for (second, (second_name, _)) in members.iter().enumerate().skip(first + 1) {
+            let count = counts[first * members.len() + second];
+            if count > 0 {
+                warn!("{count} entities have both `{first_name}` and `{second_name}`, which are disjoint");
+            }
+        }
+    }
+}
@@ -0,0 +1,316 @@
+use std::marker::PhantomData;
+
+use bevy_app::{App, Last, Plugin};
+use bevy_ecs::{
+    component::Component,
+    entity::{Entity, EntityHashMap, EntityHashSet},
+    event::{Event, EventReader},
+    observer::Trigger,
+    schedule::common_conditions::on_event,
+    system::{ResMut, Resource},
+    world::{Mut, OnAdd, OnRemove, World},
+};
+
+use crate::{visitors::variant_of, Disjoint, DisjointGroup, MemberVisitor};
+
+/// Sent when an entity changes which "variant" of the group `G` it has.
+///
+/// This is both sent as a buffered event (read it with an `EventReader`),
+/// and triggered for observers, targeting the entity.
+///
+/// Changes are collected over the frame, so removing one "variant" and adding another in the same frame
+/// results in a single event. The events of a frame are in the order of the entities, so they are the same on every run,
+/// e.g. for replays. Requires the [`VariantChangedPlugin`].
+#[derive(Event)]
+pub struct OnVariantChanged<G> {
+    /// The entity that changed.
+    pub entity: Entity,
+    /// The [`INDEX`](Disjoint::INDEX) of the "variant" the entity had before, if any.
+    pub old: Option<usize>,
+    /// The [`INDEX`](Disjoint::INDEX) of the "variant" the entity has now, if any.
+    pub new: Option<usize>,
+    _group: PhantomData<fn() -> G>,
+}
+
+impl<G> Clone for OnVariantChanged<G> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<G> Copy for OnVariantChanged<G> {}
+
+impl<G> std::fmt::Debug for OnVariantChanged<G> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OnVariantChanged")
+            .field("entity", &self.entity)
+            .field("old", &self.old)
+            .field("new", &self.new)
+            .finish()
+    }
+}
+
+/// Sent when an entity loses its last "variant" of the group `G`, so that it now matches [`None`](Disjoint::None),
+/// e.g. to despawn it or to give it a default "variant".
+///
+/// Like [`OnVariantChanged`], this is both sent as a buffered event and triggered for observers, targeting the entity,
+/// and requires the [`VariantChangedPlugin`]. Since changes are collected over the frame, swapping "variants"
+/// (removing one and adding another in the same frame) doesn't send it, and neither does despawning the entity.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::{App, Commands, Component, Trigger};
+/// use bevy_djqf::{disjoint, DisjointCommandsExt, OnBecameNone, VariantChangedPlugin};
+///
+/// #[derive(Component, Debug, Default)]
+/// struct A;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct B;
+///
+/// disjoint!(A, B);
+///
+/// let mut app = App::new();
+/// app.add_plugins(VariantChangedPlugin::<A>::default())
+///     // never leave an entity without a "variant"
+///     .observe(|trigger: Trigger<OnBecameNone<A>>, mut commands: Commands| {
+///         commands.set_variant::<A>(trigger.entity());
+///     });
+///
+/// let entity = app.world_mut().spawn(B).id();
+/// app.update();
+///
+/// app.world_mut().entity_mut(entity).remove::<B>();
+/// app.update();
+/// assert!(app.world().entity(entity).contains::<A>());
+/// ```
+#[derive(Event)]
+pub struct OnBecameNone<G> {
+    /// The entity that lost its last "variant".
+    pub entity: Entity,
+    /// The [`INDEX`](Disjoint::INDEX) of the "variant" the entity had before.
+    pub old: usize,
+    _group: PhantomData<fn() -> G>,
+}
+
+impl<G> Clone for OnBecameNone<G> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<G> Copy for OnBecameNone<G> {}
+
+impl<G> std::fmt::Debug for OnBecameNone<G> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OnBecameNone")
+            .field("entity", &self.entity)
+            .field("old", &self.old)
+            .finish()
+    }
+}
+
+/// Sends an [`OnVariantChanged`] event whenever an entity changes which "variant" of the group `G` it has.
+///
+/// `G` is any [`DisjointGroup`], e.g. the first type provided to the [`disjoint!`](crate::disjoint) macro.
+/// Changes are detected in the [`Last`] schedule. It also sends [`OnBecameNone`] when an entity loses its last "variant".
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::{App, Component, EventReader, Events};
+/// use bevy_djqf::{disjoint, DisjointCommandsExt, OnVariantChanged, VariantChangedPlugin};
+///
+/// #[derive(Component, Debug, Default)]
+/// struct A;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct B;
+///
+/// disjoint!(A, B);
+///
+/// let mut app = App::new();
+/// app.add_plugins(VariantChangedPlugin::<A>::default());
+///
+/// let entity = app.world_mut().spawn(A).id();
+/// app.update();
+///
+/// // remove `A` and add `B` in the same frame
+/// app.world_mut().commands().set_variant::<B>(entity);
+/// app.world_mut().flush();
+/// app.update();
+///
+/// let events = app.world().resource::<Events<OnVariantChanged<A>>>();
+/// let changes: Vec<_> = events
+///     .get_reader()
+///     .read(events)
+///     .map(|event| (event.old, event.new))
+///     .collect();
+/// assert_eq!(changes, [(None, Some(0)), (Some(0), Some(1))]);
+/// ```
+pub struct VariantChangedPlugin<G>(PhantomData<fn() -> G>);
+
+impl<G> Default for VariantChangedPlugin<G> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<G: DisjointGroup + 'static> Plugin for VariantChangedPlugin<G> {
+    fn build(&self, app: &mut App) {
+        app.add_event::<OnVariantChanged<G>>()
+            .add_event::<OnBecameNone<G>>()
+            .init_resource::<VariantTracker<G>>()
+            .add_systems(Last, send_variant_changed::<G>);
+        G::for_each_member(&mut ObserveMembers::<G> {
+            app,
+            _group: PhantomData,
+        });
+    }
+}
+
+/// A run condition that is true if any entity changed which "variant" of the group `G` it has, since the condition last ran.
+///
+/// This reads the [`OnVariantChanged`] events, so it requires the [`VariantChangedPlugin`]. They are sent in the [`Last`] schedule,
+/// so a change is seen by the condition in the frame after it happens. Removals are included, since the plugin observes them
+/// as they happen, rather than relying on `RemovedComponents`.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::{App, Component, IntoSystemConfigs, ResMut, Resource, Update};
+/// use bevy_djqf::{disjoint, on_variant_changed, DisjointCommandsExt, VariantChangedPlugin};
+///
+/// #[derive(Component, Debug, Default)]
+/// struct A;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct B;
+///
+/// disjoint!(A, B);
+///
+/// #[derive(Resource, Default)]
+/// struct Runs(usize);
+///
+/// let mut app = App::new();
+/// app.add_plugins(VariantChangedPlugin::<A>::default())
+///     .init_resource::<Runs>()
+///     .add_systems(
+///         Update,
+///         (|mut runs: ResMut<Runs>| runs.0 += 1).run_if(on_variant_changed::<A>()),
+///     );
+///
+/// let entity = app.world_mut().spawn(A).id();
+/// app.update();
+/// app.update();
+/// assert_eq!(app.world().resource::<Runs>().0, 1);
+///
+/// // nothing changed
+/// app.update();
+/// assert_eq!(app.world().resource::<Runs>().0, 1);
+///
+/// app.world_mut().commands().set_variant::<B>(entity);
+/// app.world_mut().flush();
+/// app.update();
+/// app.update();
+/// assert_eq!(app.world().resource::<Runs>().0, 2);
+///
+/// // removing the "variant" is a change too
+/// app.world_mut().entity_mut(entity).remove::<B>();
+/// app.update();
+/// app.update();
+/// assert_eq!(app.world().resource::<Runs>().0, 3);
+/// ```
+pub fn on_variant_changed<G: DisjointGroup + 'static>(
+) -> impl FnMut(EventReader<OnVariantChanged<G>>) -> bool + Clone {
+    on_event::<OnVariantChanged<G>>()
+}
+
+/// A run condition that is true if any entity lost its last "variant" of the group `G`, since the condition last ran.
+///
+/// This reads the [`OnBecameNone`] events, so it requires the [`VariantChangedPlugin`], and as with [`on_variant_changed`],
+/// a change is seen by the condition in the frame after it happens.
+pub fn on_became_none<G: DisjointGroup + 'static>(
+) -> impl FnMut(EventReader<OnBecameNone<G>>) -> bool + Clone {
+    on_event::<OnBecameNone<G>>()
+}
+
+/// The entities that may have changed this frame, and the last known "variant" of every entity.
+#[derive(Resource)]
+struct VariantTracker<G> {
+    dirty: EntityHashSet,
+    variants: EntityHashMap<usize>,
+    _group: PhantomData<fn() -> G>,
+}
+
+impl<G> Default for VariantTracker<G> {
+    fn default() -> Self {
+        Self {
+            dirty: EntityHashSet::default(),
+            variants: EntityHashMap::default(),
+            _group: PhantomData,
+        }
+    }
+}
+
+/// Adds observers marking entities as dirty when any member is added or removed.
+struct ObserveMembers<'a, G> {
+    app: &'a mut App,
+    _group: PhantomData<fn() -> G>,
+}
+
+impl<G: DisjointGroup + 'static> MemberVisitor for ObserveMembers<'_, G> {
+    fn visit<M: Disjoint + Component>(&mut self) {
+        self.app
+            .observe(mark_dirty::<G, OnAdd, M>)
+            .observe(mark_dirty::<G, OnRemove, M>);
+    }
+}
+
+fn mark_dirty<G: DisjointGroup + 'static, E: Event, M: Component>(
+    trigger: Trigger<E, M>,
+    mut tracker: ResMut<VariantTracker<G>>,
+) {
+    tracker.dirty.insert(trigger.entity());
+}
+
+fn send_variant_changed<G: DisjointGroup + 'static>(world: &mut World) {
+    let mut changes = Vec::<OnVariantChanged<G>>::new();
+    let mut became_none = Vec::<OnBecameNone<G>>::new();
+    world.resource_scope(|world, mut tracker: Mut<VariantTracker<G>>| {
+        let tracker = &mut *tracker;
+        // the set has no order, so sort the entities for the events to be in the same order on every run
+        let mut dirty = tracker.dirty.drain().collect::<Vec<_>>();
+        dirty.sort_unstable();
+        for entity in dirty {
+            let entity_ref = world.get_entity(entity);
+            let new = entity_ref.and_then(|entity| variant_of::<G>(&entity));
+            let old = match new {
+                Some(new) => tracker.variants.insert(entity, new),
+                None => tracker.variants.remove(&entity),
+            };
+            if old != new {
+                changes.push(OnVariantChanged {
+                    entity,
+                    old,
+                    new,
+                    _group: PhantomData,
+                });
+            }
+            // despawned entities don't need cleaning up
+            if let (Some(old), None, Some(_)) = (old, new, entity_ref) {
+                became_none.push(OnBecameNone {
+                    entity,
+                    old,
+                    _group: PhantomData,
+                });
+            }
+        }
+    });
+    for change in changes {
+        world.send_event(change);
+        world.trigger_targets(change, change.entity);
+    }
+    for change in became_none {
+        world.send_event(change);
+        world.trigger_targets(change, change.entity);
+    }
+}
@@ -0,0 +1,113 @@
+use bevy_ecs::{archetype::Archetypes, component::Components, world::World};
+use bevy_utils::tracing::info;
+
+use crate::{
+    visitors::{group_name, member_ids},
+    DisjointGroup,
+};
+
+/// The number of entities with only each "variant" of the group `G`, paired with its [`NAME`](crate::Disjoint::NAME),
+/// in the order of [`NAMES`](DisjointGroup::NAMES), e.g. for balancing or debugging. An index without a "variant" is skipped.
+///
+/// Each count is the number of entities matching [`Only`](crate::Disjoint::Only) for that "variant".
+/// See [`log_variant_histogram`] to log them as a table.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::{Component, World};
+/// use bevy_djqf::{disjoint, variant_histogram};
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Player;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Enemy;
+///
+/// disjoint!(Player, Enemy);
+///
+/// let mut world = World::new();
+/// world.spawn(Player);
+/// world.spawn(Enemy);
+/// world.spawn(Enemy);
+///
+/// assert_eq!(variant_histogram::<Player>(&world), [("Player", 1), ("Enemy", 2)]);
+/// ```
+pub fn variant_histogram<G: DisjointGroup>(world: &World) -> Vec<(&'static str, usize)> {
+    G::NAMES
+        .iter()
+        .copied()
+        .zip(count_only::<G>(world.archetypes(), world.components()))
+        .filter(|(name, _)| !name.is_empty())
+        .collect()
+}
+
+/// A system that logs the [`variant_histogram`] of the group `G` as a table, at the `info` level.
+///
+/// It counts every entity each time it runs, so it is meant to run on demand, e.g. on a key press
+/// with `log_variant_histogram::<G>.run_if(input_just_pressed(KeyCode::F3))`, or once with `RunSystemOnce`.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::{Component, World};
+/// # use bevy::ecs::system::RunSystemOnce;
+/// use bevy_djqf::{disjoint, log_variant_histogram};
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Player;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Enemy;
+///
+/// disjoint!(Player, Enemy);
+///
+/// let mut world = World::new();
+/// world.spawn(Player);
+/// world.spawn(Enemy);
+///
+/// // logs:
+/// // entities with only each "variant" of `Player`:
+/// //   Player | 1
+/// //   Enemy  | 1
+/// world.run_system_once(log_variant_histogram::<Player>);
+/// ```
+pub fn log_variant_histogram<G: DisjointGroup>(archetypes: &Archetypes, components: &Components) {
+    let counts = count_only::<G>(archetypes, components);
+    let name_width = G::NAMES.iter().map(|name| name.len()).max().unwrap_or(0);
+    let count_width = counts
+        .iter()
+        .map(|count| count.to_string().len())
+        .max()
+        .unwrap_or(0);
+    let mut table = format!(
+        "entities with only each \"variant\" of `{}`:",
+        group_name::<G>()
+    );
+    for (name, count) in G::NAMES.iter().zip(counts) {
+        if name.is_empty() {
+            continue;
+        }
+        table.push_str(&format!("\n  {name:<name_width$} | {count:>count_width$}"));
+    }
+    info!("{table}");
+}
+
+/// The number of entities with only each "variant" of `G`, in order, with `0` for an index without a "variant".
+pub(crate) fn count_only<G: DisjointGroup>(
+    archetypes: &Archetypes,
+    components: &Components,
+) -> Vec<usize> {
+    let members = member_ids::<G>(components);
+    let mut counts = vec![0; members.len()];
+    for archetype in archetypes.iter() {
+        // entities in the same archetype have the same components, so check the archetype once
+        let mut present = members
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, id))| id.is_some_and(|id| archetype.contains(id)))
+            .map(|(index, _)| index);
+        if let (Some(index), None) = (present.next(), present.next()) {
+            counts[index] += archetype.len();
+        }
+    }
+    counts
+}
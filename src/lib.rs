@@ -1,17 +1,2632 @@
 #![doc = include_str!("../README.md")]
 
+use bevy_ecs::{component::Component, query::QueryFilter};
+
+mod app;
+mod commands;
+#[cfg(feature = "diagnostics")]
+mod diagnostics;
+mod dynamic;
+#[cfg(feature = "egui")]
+mod egui;
+mod enforce;
+mod events;
+mod histogram;
+mod migrate;
+mod partition;
+mod per_variant;
+mod query;
+#[cfg(feature = "reflect")]
+mod reflect;
+mod registry;
+#[cfg(feature = "serde")]
+mod serde;
+#[cfg(feature = "states")]
+mod states;
+mod visitors;
+
+pub use app::{DisjointAppExt, DisjointGroupConfig};
+pub use commands::{
+    DefaultMembers, DisjointCommandsExt, DisjointEntityCommandsExt, DisjointEntityWorldMutExt,
+    OutOfRange, UnknownVariant,
+};
+#[cfg(feature = "diagnostics")]
+pub use diagnostics::DisjointDiagnosticsPlugin;
+pub use dynamic::DynamicDisjoint;
+#[cfg(feature = "egui")]
+pub use egui::variant_picker;
+pub use enforce::{check_disjoint, DisjointCheckPlugin, DisjointEnforcePlugin, OnViolation};
+pub use events::{
+    on_became_none, on_variant_changed, OnBecameNone, OnVariantChanged, VariantChangedPlugin,
+};
+pub use histogram::{log_variant_histogram, variant_histogram};
+pub use migrate::migrate_from_enum;
+pub use partition::{check_partitions, check_systems_disjoint};
+pub use per_variant::{PerVariant, VariantArray};
+pub use query::{
+    only_query_state, AnyVariantOf, DisjointData, DisjointPair, DisjointQuery,
+    ExclusiveVariantQuery, VariantOf, VariantReader,
+};
+#[cfg(feature = "reflect")]
+pub use reflect::{DisjointReflectAppExt, ReflectMembers};
+pub use registry::{DisjointRegistry, DisjointRegistryAppExt};
+#[cfg(feature = "serde")]
+pub use serde::{deserialize_variant, serialize_variant, ApplyVariant};
+#[cfg(feature = "states")]
+pub use states::{mirror_state_to_markers, MirrorState};
+
+/// Generate a marker component for each variant of a fieldless enum, and make them disjoint with [`disjoint!`].
+///
+/// The enum itself is left as is, as the source of truth for the list of "variants".
+/// Each marker is a unit struct with the same name and visibility as the variant,
+/// and derives `Component`, `Debug` and `Default`. Doc comments and `#[deprecated]` on variants are kept on the markers,
+/// so a retired variant stays in the group, but using its marker directly warns.
+/// With the `reflect` feature, the markers also derive `Reflect`, see `DisjointReflectAppExt`.
+///
+/// A `{Enum}Any` type alias with the same visibility, e.g. `GameObjectAny`, is also generated for the [`Any`](Disjoint::Any) filter,
+/// which is the same for every marker, so that no particular marker needs to be picked.
+/// Likewise, `{Enum}None`, e.g. `GameObjectNone`, is the [`None`](Disjoint::None) filter, i.e. `<Player as Disjoint>::None` for any marker.
+///
+/// Only unit variants are supported. Requires the `derive` feature.
+///
+/// To make the markers from an existing enum, e.g. `enum Kind { A, B }` that isn't used as a component,
+/// add the derive to it, so the enum stays the single source of truth. There is no macro that takes only
+/// the path of the enum, such as `disjoint_from_enum!(Kind)`, because a macro only sees the tokens it is given,
+/// and can't look up the variants of an enum defined elsewhere. For the same reason,
+/// an enum from another crate can't be used, and the variants have to be listed with [`disjoint!`] instead.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::{App, Update, Query, Transform};
+/// use bevy_djqf::Disjoint;
+///
+/// // Generates the `Player`, `Enemy` and `Wall` marker components
+/// #[derive(bevy_djqf::Disjoint)]
+/// enum GameObject {
+///     /// The player
+///     Player,
+///     Enemy,
+///     Wall,
+/// }
+///
+/// fn player_only(_query: Query<&mut Transform, <Player as Disjoint>::Only>) {}
+///
+/// fn not_player(_query: Query<&mut Transform, <Player as Disjoint>::Other>) {}
+///
+/// fn any_object(_query: Query<&mut Transform, GameObjectAny>) {}
+///
+/// fn untagged(_query: Query<&mut Transform, GameObjectNone>) {}
+///
+/// assert_eq!(<Wall as Disjoint>::INDEX, 2);
+/// let _: std::marker::PhantomData<GameObjectAny> = std::marker::PhantomData::<<Enemy as Disjoint>::Any>;
+/// let _: std::marker::PhantomData<GameObjectNone> = std::marker::PhantomData::<<Wall as Disjoint>::None>;
+/// # App::new().add_systems(Update, (player_only, not_player, any_object, untagged));
+/// ```
+///
+/// The enum implements [`DisjointEnum`], which converts between its values and the marker components,
+/// and iterates over them with [`DisjointEnum::iter`]. An `ALL` constant with every value is also generated,
+/// and an `INFO` constant with the [`DisjointInfo`] of the group. It also implements [`Display`](std::fmt::Display)
+/// with the name of the variant from [`NAMES`](DisjointGroup::NAMES), e.g. for logs, which matches a derived `Debug`:
+/// ```
+/// # use bevy::prelude::{Commands, World};
+/// # use bevy::ecs::{system::RunSystemOnce, world::CommandQueue};
+/// use bevy_djqf::{Disjoint, DisjointEntityCommandsExt, DisjointEnum, DisjointQuery};
+///
+/// #[derive(bevy_djqf::Disjoint, Clone, Copy, Debug, PartialEq)]
+/// enum GameObject {
+///     Player,
+///     Enemy,
+///     Wall,
+/// }
+///
+/// assert_eq!(GameObject::Enemy.index(), <Enemy as Disjoint>::INDEX);
+/// assert_eq!(GameObject::from_index(<Wall as Disjoint>::INDEX), Some(GameObject::Wall));
+/// assert_eq!(GameObject::from_index(3), None);
+///
+/// // every value, in the order of the variants
+/// assert_eq!(GameObject::ALL, [GameObject::Player, GameObject::Enemy, GameObject::Wall]);
+/// assert_eq!(GameObject::iter().collect::<Vec<_>>(), GameObject::ALL);
+/// assert_eq!(GameObject::INFO.names, ["Player", "Enemy", "Wall"]);
+/// assert_eq!(GameObject::Enemy.to_string(), "Enemy");
+/// assert_eq!(format!("{:?}", GameObject::Enemy), "Enemy");
+///
+/// let mut world = World::new();
+/// let entity = world.spawn(Player).id();
+///
+/// let mut queue = CommandQueue::default();
+/// Commands::new(&mut queue, &world)
+///     .entity(entity)
+///     .set_variant_value(GameObject::Wall);
+/// queue.apply(&mut world);
+/// assert!(!world.entity(entity).contains::<Player>());
+///
+/// let value = world.run_system_once(move |query: DisjointQuery<<GameObject as DisjointEnum>::Group>| {
+///     query.variant(entity).and_then(GameObject::from_index)
+/// });
+/// assert_eq!(value, Some(GameObject::Wall));
+/// ```
+///
+/// For compact encodings, e.g. in network packets, the enum also converts to and from its [`INDEX`](Disjoint::INDEX)
+/// as a `u8`, with [`TryFrom`] returning [`OutOfRange`] for an index without a variant.
+/// Enums with more than 256 variants use a `u16` instead.
+/// ```
+/// use bevy_djqf::OutOfRange;
+///
+/// #[derive(bevy_djqf::Disjoint, Clone, Copy, Debug, PartialEq)]
+/// enum GameObject {
+///     Player,
+///     Enemy,
+///     Wall,
+/// }
+///
+/// assert_eq!(u8::from(GameObject::Wall), 2);
+/// assert_eq!(GameObject::try_from(1u8), Ok(GameObject::Enemy));
+/// assert_eq!(GameObject::try_from(3u8), Err(OutOfRange { index: 3, count: 3 }));
+/// ```
+///
+/// A `{Enum}Mask` type, e.g. `GameObjectMask`, is also generated for sets of values, with one bit for each "variant".
+/// It can filter a [`QueryBuilder`](bevy_ecs::query::QueryBuilder) to the entities with any of its "variants",
+/// like [`disjoint_subset!`] for a subset that is only known at runtime, e.g. from a config file:
+/// ```
+/// # use bevy::prelude::{Entity, World};
+/// # use bevy::ecs::query::QueryBuilder;
+/// use bevy_djqf::Disjoint;
+///
+/// #[derive(bevy_djqf::Disjoint, Clone, Copy, Debug, PartialEq)]
+/// enum GameObject {
+///     Player,
+///     Enemy,
+///     Wall,
+/// }
+///
+/// let mask = GameObjectMask::from_iter([GameObject::Player, GameObject::Wall]);
+/// assert!(mask.contains(<Wall as Disjoint>::INDEX));
+/// assert!(!mask.contains(<Enemy as Disjoint>::INDEX));
+/// assert_eq!(mask.iter().collect::<Vec<_>>(), [0, 2]);
+///
+/// let mut world = World::new();
+/// let player = world.spawn(Player).id();
+/// world.spawn(Enemy);
+/// let wall = world.spawn(Wall).id();
+///
+/// let mut query = QueryBuilder::<Entity>::new(&mut world);
+/// mask.filter(&mut query);
+/// let mut query = query.build();
+/// assert_eq!(query.iter(&world).collect::<Vec<_>>(), [player, wall]);
+/// ```
+///
+/// Using a deprecated marker warns, while the filters of the group still include it:
+/// ```
+/// # use bevy::prelude::{Entity, World};
+/// use bevy_djqf::Disjoint;
+///
+/// #[derive(bevy_djqf::Disjoint)]
+/// enum GameObject {
+///     Player,
+///     #[deprecated = "use `Player` instead"]
+///     Hero,
+/// }
+///
+/// let mut world = World::new();
+/// // e.g. loaded from an old save
+/// #[allow(deprecated)]
+/// let hero = world.spawn(Hero).id();
+///
+/// let mut players = world.query_filtered::<Entity, <Player as Disjoint>::Only>();
+/// assert_eq!(players.iter(&world).count(), 0);
+/// let mut any = world.query_filtered::<Entity, GameObjectAny>();
+/// assert_eq!(any.iter(&world).collect::<Vec<_>>(), [hero]);
+/// ```
+/// ```compile_fail
+/// #![deny(deprecated)]
+///
+/// #[derive(bevy_djqf::Disjoint)]
+/// enum GameObject {
+///     Player,
+///     #[deprecated = "use `Player` instead"]
+///     Hero,
+/// }
+///
+/// let _hero = Hero;
+/// ```
+///
+/// Variants with fields are rejected:
+/// ```compile_fail
+/// #[derive(bevy_djqf::Disjoint)]
+/// enum GameObject {
+///     Player(u32),
+///     Enemy,
+/// }
+/// ```
+#[cfg(feature = "derive")]
+pub use bevy_djqf_macros::Disjoint;
+
+/// Macros for the filters of a "variant" in type position, e.g. `only!(Player)` for `<Player as Disjoint>::Only`.
+///
+/// Import them from here, e.g. `use bevy_djqf::filters::{only, other};`.
+pub mod filters {
+    #[doc(inline)]
+    pub use crate::__djqf_any as any;
+    #[doc(inline)]
+    pub use crate::__djqf_none as none;
+    #[doc(inline)]
+    pub use crate::__djqf_not as not;
+    #[doc(inline)]
+    pub use crate::__djqf_only as only;
+    #[doc(inline)]
+    pub use crate::__djqf_other as other;
+}
+
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::__disjoint_reflect as reflect_markers;
+    pub use crate::__disjoint_reflect_members as reflect_members;
+    pub use crate::__disjoint_register_reflected as register_reflected;
+    #[cfg(feature = "derive")]
+    pub use bevy_djqf_macros::{disjoint_aliases, disjoint_unique};
+    // without the proc macros, repeated types are only caught by the conflicting impls
+    #[cfg(not(feature = "derive"))]
+    pub use crate::__disjoint_unique as disjoint_unique;
+    #[cfg(feature = "reflect")]
+    pub use bevy_app::App;
+    pub use bevy_ecs::component::{Component, ComponentHooks, StorageType};
+    // the query filters, for paths in the macros that don't need `bevy_ecs` as a dependency
+    pub use bevy_ecs::query;
+    #[cfg(feature = "reflect")]
+    pub use bevy_ecs::reflect::ReflectComponent;
+    pub use bevy_ecs::system::{Query, SystemParam};
+    pub use bevy_ecs::world::EntityWorldMut;
+    #[cfg(feature = "reflect")]
+    pub use bevy_reflect::{FromType, GetTypeRegistration, Reflect, TypePath};
+
+    /// Calls [`ReflectMembers::register`](crate::ReflectMembers::register) for `G` with [`RegisterMembers`]
+    /// if `G` implements it, and does nothing with [`RegisterNothing`] otherwise.
+    #[cfg(feature = "reflect")]
+    pub struct ReflectProbe<G>(pub core::marker::PhantomData<fn() -> G>);
+
+    #[cfg(feature = "reflect")]
+    pub trait RegisterMembers {
+        fn register_members(&self, app: &mut App);
+    }
+
+    #[cfg(feature = "reflect")]
+    impl<G: crate::ReflectMembers> RegisterMembers for ReflectProbe<G> {
+        fn register_members(&self, app: &mut App) {
+            G::register(app);
+        }
+    }
+
+    #[cfg(feature = "reflect")]
+    pub trait RegisterNothing {
+        fn register_members(&self, app: &mut App);
+    }
+
+    #[cfg(feature = "reflect")]
+    impl<G> RegisterNothing for &ReflectProbe<G> {
+        fn register_members(&self, _app: &mut App) {}
+    }
+
+    /// The [`Only`](crate::Disjoint::Only) filter and a `Without` filter of a member of the group `G`,
+    /// used by [`disjoint_subset!`](crate::disjoint_subset) and [`disjoint_except!`](crate::disjoint_except)
+    /// to check that all of the "variants" are from the same group.
+    ///
+    /// This is implemented by [`disjoint!`](crate::disjoint) for each member, rather than for every [`GroupMember`](crate::GroupMember),
+    /// so that a "variant" from another group is reported with this message, rather than as a mismatched `Group`.
+    #[diagnostic::on_unimplemented(
+        message = "`{Self}` is not a member of the group `{G}`",
+        label = "not in the same group as the first \"variant\"",
+        note = "all of the \"variants\" must be provided to the same `disjoint!` macro"
+    )]
+    pub trait SubsetMember<G> {
+        type Only;
+        type Without;
+        /// Every member of the group, in the order they were provided, as [`Included`] for this member
+        /// and as [`Excluded`] for the others, nested in pairs and ending with the marker provided with `in`, if any.
+        type Mask;
+    }
+
+    /// A member in a [`SubsetMember::Mask`] that is one of the "variants" provided to [`disjoint_subset!`](crate::disjoint_subset).
+    pub struct Included<T>(core::marker::PhantomData<T>);
+
+    /// A member in a [`SubsetMember::Mask`] that isn't one of the "variants" provided to [`disjoint_subset!`](crate::disjoint_subset).
+    pub struct Excluded<T>(core::marker::PhantomData<T>);
+
+    /// The marker provided with `in` at the end of a [`SubsetMember::Mask`].
+    pub struct Required<T>(core::marker::PhantomData<T>);
+
+    /// Combines the masks of two members of the same group, so that a member is [`Included`] if it is in either.
+    pub trait Union<M> {
+        type Output;
+    }
+
+    impl Union<()> for () {
+        type Output = ();
+    }
+
+    impl<A: Union<B>, B, R: Union<S>, S> Union<(B, S)> for (A, R) {
+        type Output = (A::Output, R::Output);
+    }
+
+    impl<T> Union<Included<T>> for Included<T> {
+        type Output = Included<T>;
+    }
+
+    impl<T> Union<Excluded<T>> for Included<T> {
+        type Output = Included<T>;
+    }
+
+    impl<T> Union<Included<T>> for Excluded<T> {
+        type Output = Included<T>;
+    }
+
+    impl<T> Union<Excluded<T>> for Excluded<T> {
+        type Output = Excluded<T>;
+    }
+
+    impl<T> Union<Required<T>> for Required<T> {
+        type Output = Required<T>;
+    }
+
+    /// The filter for a mask: `Without` each [`Excluded`] member, and `With` the [`Required`] marker.
+    pub trait MaskFilter {
+        type Filter;
+    }
+
+    impl MaskFilter for () {
+        type Filter = ();
+    }
+
+    impl<A: MaskFilter, R: MaskFilter> MaskFilter for (A, R) {
+        type Filter = (A::Filter, R::Filter);
+    }
+
+    impl<T> MaskFilter for Included<T> {
+        type Filter = ();
+    }
+
+    impl<T: Component> MaskFilter for Excluded<T> {
+        type Filter = query::Without<T>;
+    }
+
+    impl<T: Component> MaskFilter for Required<T> {
+        type Filter = query::With<T>;
+    }
+
+    /// Only compiles if `B` is in the same group as `A`, used by [`assert_disjoint!`](crate::assert_disjoint),
+    /// [`per_variant_systems!`](crate::per_variant_systems) and [`disjoint_queries!`](crate::disjoint_queries).
+    pub fn same_group<A: crate::Disjoint, B: crate::GroupMember<A::Group>>() {}
+
+    /// Panics if an index is given more than once,
+    /// used by [`disjoint!`](crate::disjoint) to check explicit indices at compile time.
+    pub const fn check_indices(indices: &[usize]) {
+        let mut i = 0;
+        while i < indices.len() {
+            let mut j = 0;
+            while j < i {
+                if indices[i] == indices[j] {
+                    panic!("each index must only be given once to `disjoint!`");
+                }
+                j += 1;
+            }
+            i += 1;
+        }
+    }
+
+    /// One more than the largest index, which is the [`COUNT`](crate::DisjointGroup::COUNT) of a group with explicit indices,
+    /// used by [`disjoint!`](crate::disjoint).
+    pub const fn count_indices(indices: &[usize]) -> usize {
+        let mut count = 0;
+        let mut i = 0;
+        while i < indices.len() {
+            if indices[i] >= count {
+                count = indices[i] + 1;
+            }
+            i += 1;
+        }
+        count
+    }
+}
+
+#[cfg(not(feature = "derive"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __disjoint_unique {
+    ( $($types:tt)* ) => {};
+}
+
+// with the `reflect` feature, `make_disjoint_markers!` adds `Reflect` to the derives given to it, unless it is already there
+#[cfg(feature = "reflect")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __disjoint_reflect {
+    ( $vis:tt [ $($common:tt)* ] [ Reflect $($derives:tt)* ] $($markers:tt)* ) => {
+        $crate::make_disjoint_markers!(@markers $vis [ $($common)* ] $($markers)*);
+    };
+
+    ( $vis:tt $common:tt [ $derive:tt $($derives:tt)* ] $($markers:tt)* ) => {
+        $crate::__disjoint_reflect!($vis $common [ $($derives)* ] $($markers)*);
+    };
+
+    // generic markers would need bounds for reflection, so they are left as they are
+    ( $vis:tt [ $($common:tt)* ] [] $type_template_macro:ident for < $($markers:tt)* ) => {
+        $crate::make_disjoint_markers!(@markers $vis [ $($common)* ] $type_template_macro for < $($markers)*);
+    };
+
+    ( $vis:tt [ $($common:tt)* ] [] $($markers:tt)* ) => {
+        $crate::make_disjoint_markers!(@markers $vis [ $($common)* #[derive($crate::__private::Reflect)] ] $($markers)*);
+    };
+}
+
+// with the `reflect` feature, `disjoint!` implements `ReflectMembers` for groups whose members can all be reflected
+#[cfg(feature = "reflect")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __disjoint_reflect_members {
+    ( [ $( $param:ident ),* ] [ $($predicate:tt)* ] $group:ty; $( $member:ty ),+ ) => {
+        // higher-ranked bounds, as for `DefaultMembers`
+        #[allow(deprecated)]
+        impl< $( $param ),* > $crate::ReflectMembers for $group
+        where
+            $(
+                for<'__a> $member: $crate::__private::Reflect
+                    + $crate::__private::TypePath
+                    + $crate::__private::GetTypeRegistration,
+                for<'__a> $crate::__private::ReflectComponent: $crate::__private::FromType<$member>,
+            )+
+            $($predicate)*
+        {
+            fn register(app: &mut $crate::__private::App) {
+                $(
+                    app.register_type::<$member>()
+                        .register_type_data::<$member, $crate::__private::ReflectComponent>();
+                )+
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "reflect"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __disjoint_reflect_members {
+    ( $($tokens:tt)* ) => {};
+}
+
+// with the `reflect` feature, `disjoint!` registers the members in `add_disjoint_group` if the group implements `ReflectMembers`,
+// picking `RegisterMembers` over `RegisterNothing` by autoref, since the impl of `ReflectMembers` may not apply
+#[cfg(feature = "reflect")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __disjoint_register_reflected {
+    () => {
+        fn __register_reflected(app: &mut $crate::__private::App) {
+            use $crate::__private::{RegisterMembers as _, RegisterNothing as _};
+            (&$crate::__private::ReflectProbe::<Self>(::core::marker::PhantomData))
+                .register_members(app);
+        }
+    };
+}
+
+#[cfg(not(feature = "reflect"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __disjoint_register_reflected {
+    () => {};
+}
+
+#[cfg(not(feature = "reflect"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __disjoint_reflect {
+    ( $vis:tt $common:tt $derives:tt $($markers:tt)* ) => {
+        $crate::make_disjoint_markers!(@markers $vis $common $($markers)*);
+    };
+}
+
 /// Generate disjoint query filters for the provided list of types.
 ///
-/// Alternatively, you can generate the types in one step using the [`make_disjoint_markers`] macro.
+/// Alternatively, you can generate the types in one step using the [`make_disjoint_markers`] macro.
+///
+/// # Usage
+///
+/// `disjoint!(A, B);`
+///
+/// A trailing comma is allowed, e.g. `disjoint!(A, B,);`.
+/// At least two types must be provided, so `disjoint!()`, `disjoint!(A)` and `disjoint!(A,)` fail to compile.
+///
+/// Each type must only be provided once, so `disjoint!(A, B, A)` fails to compile,
+/// with "`A` is provided more than once to `disjoint!`" when the `derive` feature is enabled.
+///
+/// One type can be marked as the default "variant" with `#[default]`, e.g. `disjoint!(#[default] A, B);`,
+/// which implements [`DefaultVariant`] for the group, for [`DisjointCommandsExt::spawn_default`].
+/// Only one type can be the default:
+/// ```compile_fail
+/// # #[derive(bevy::prelude::Component, Default)]
+/// # struct A;
+/// # #[derive(bevy::prelude::Component, Default)]
+/// # struct B;
+/// bevy_djqf::disjoint!(#[default] A, #[default] B);
+/// ```
+///
+/// The [`INDEX`](Disjoint::INDEX) of each type is its position, unless every type is given an explicit index,
+/// e.g. `disjoint!(Player = 0, Wall = 2, Enemy = 1);`, so that a new type can be added anywhere in the list
+/// without changing the indices of the others, e.g. for serialized save data.
+/// [`NAMES`](DisjointGroup::NAMES) and [`for_each_member`](DisjointGroup::for_each_member) are in the order of the indices.
+/// Explicit indices can't be combined with `#[default]`, `in`, `cfg`-gated types or generic types.
+///
+/// Each index must only be given once, but there can be gaps, e.g. to retire a "variant" without reusing its index.
+/// [`COUNT`](Disjoint::COUNT) is one more than the largest index, so with gaps it is more than the number of types:
+/// the [`NAMES`](DisjointGroup::NAMES) of an index without a "variant" is `""`, its slot in an [`Array`](DisjointGroup::Array)
+/// (and so in a [`PerVariant`]) is unused, and the runtime helpers skip it, e.g. [`set_variant_by_index`](DisjointEntityCommandsExt::set_variant_by_index)
+/// returns [`OutOfRange`] for it.
+/// ```compile_fail
+/// # #[derive(bevy::prelude::Component)]
+/// # struct A;
+/// # #[derive(bevy::prelude::Component)]
+/// # struct B;
+/// bevy_djqf::disjoint!(A = 0, B = 0);
+/// ```
+///
+/// Types can be `cfg`-gated, e.g. `disjoint!(#[cfg(feature = "special")] Special, Player, Enemy);`.
+/// When the `cfg` is off, the type isn't a member of the group, and the other filters don't refer to it.
+/// This expands to one `disjoint!` for each combination of the `cfg`s, so keep the number of gated types small.
+/// It can't be combined with `#[default]`, or with generic types.
+///
+/// Bevy only implements query filters for tuples of up to 15 filters, so for larger groups
+/// the filters past the 14th are nested in the last position of the tuple (or of the `Or`), which has the same meaning.
+///
+/// Large groups are supported too, e.g. hundreds of types, within the default recursion limit.
+///
+/// A group of generic types is declared with its generic parameters first, e.g. `disjoint!(<T: Send + Sync + 'static> Height<T>, Width<T>);`,
+/// which makes `Height<T>` and `Width<T>` disjoint for every `T`. Simple bounds (each a single token, like `Send` or `'static`) can be given inline,
+/// and any others in a trailing where clause, e.g. `disjoint!(<T> Height<T>, Width<T> where T: std::fmt::Debug + Send + Sync + 'static);`.
+/// The [`NAME`](Disjoint::NAME) of each type is as written, e.g. `"Height<T>"` for every `T`.
+///
+/// To only apply the filters to entities with a marker for the whole group, e.g. a `GameObject` tag,
+/// provide it first with `disjoint!(in GameObject; Player, Enemy);`. Each of the [`Any`](Disjoint::Any),
+/// [`Other`](Disjoint::Other), [`Only`](Disjoint::Only) and [`None`](Disjoint::None) filters then also requires `With<GameObject>`,
+/// so unrelated entities don't match, not even `None`. It can't be combined with `#[default]`, `cfg`-gated types or generic types.
+///
+/// The query filters refer to `bevy_ecs` through this crate, so only `bevy` (or `bevy_ecs`) needs to be a dependency.
+/// To use the query filters of another path to the ECS crate instead, e.g. a crate that wraps or re-exports bevy,
+/// provide it with `disjoint!(crate_path = my_bevy::ecs; A, B);`.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::{App, Component, Update, Query, Transform};
+/// use bevy_djqf::{Disjoint, disjoint};
+///
+/// #[derive(Component, Debug, Default)]
+/// struct A;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct B;
+///
+/// disjoint!(A, B);
+///
+/// fn only_a(_query: Query<&mut Transform, <A as Disjoint>::Only>) {}
+///
+/// fn except_a(_query: Query<&mut Transform, <A as Disjoint>::Other>) {}
+///
+/// # App::new().add_systems(Update, (only_a, except_a));
+/// ```
+///
+/// # Example with paths
+///
+/// Types can be referred to by their path, so they can live in other modules.
+/// ```
+/// # use bevy::prelude::{Component, World};
+/// use bevy_djqf::{Disjoint, disjoint};
+///
+/// mod enemies {
+///     # use bevy::prelude::Component;
+///     #[derive(Component, Debug, Default)]
+///     pub struct Goblin;
+///
+///     #[derive(Component, Debug, Default)]
+///     pub struct Orc;
+/// }
+///
+/// mod allies {
+///     # use bevy::prelude::Component;
+///     #[derive(Component, Debug, Default)]
+///     pub struct Knight;
+/// }
+///
+/// disjoint!(enemies::Goblin, enemies::Orc, allies::Knight);
+///
+/// let mut world = World::new();
+/// world.spawn(enemies::Goblin);
+/// world.spawn(enemies::Orc);
+/// world.spawn(allies::Knight);
+///
+/// let mut goblins = world.query_filtered::<(), <enemies::Goblin as Disjoint>::Only>();
+/// assert_eq!(goblins.iter(&world).count(), 1);
+///
+/// let mut not_goblins = world.query_filtered::<(), <enemies::Goblin as Disjoint>::Other>();
+/// assert_eq!(not_goblins.iter(&world).count(), 2);
+/// ```
+///
+/// # Example with generic types
+///
+/// Each instantiation of a generic type is a separate type, so they can be made disjoint too.
+/// ```
+/// # use bevy::prelude::{Component, World};
+/// # use std::marker::PhantomData;
+/// use bevy_djqf::{Disjoint, disjoint};
+///
+/// #[derive(Debug, Default)]
+/// struct Red;
+///
+/// #[derive(Debug, Default)]
+/// struct Blue;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Team<T: Send + Sync + 'static>(PhantomData<T>);
+///
+/// disjoint!(Team<Red>, Team<Blue>);
+///
+/// let mut world = World::new();
+/// world.spawn(Team::<Red>::default());
+/// world.spawn(Team::<Blue>::default());
+/// world.spawn((Team::<Red>::default(), Team::<Blue>::default()));
+///
+/// let mut red = world.query_filtered::<(), <Team<Red> as Disjoint>::Only>();
+/// assert_eq!(red.iter(&world).count(), 1);
+///
+/// assert_eq!(<Team<Blue> as Disjoint>::NAME, "Team<Blue>");
+/// ```
+///
+/// # Example with `cfg`-gated types
+/// ```
+/// # use bevy::prelude::{Component, Or, With, Without};
+/// # use std::marker::PhantomData;
+/// use bevy_djqf::{disjoint, Disjoint};
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Special;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Player;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Enemy;
+///
+/// // `any()` is always off
+/// disjoint!(#[cfg(any())] Special, Player, Enemy);
+///
+/// fn same_type<T>(_: PhantomData<T>, _: PhantomData<T>) {}
+/// same_type(
+///     PhantomData::<<Player as Disjoint>::Other>,
+///     PhantomData::<(Without<Player>, With<Enemy>)>,
+/// );
+/// assert_eq!(<Player as Disjoint>::NAMES, ["Player", "Enemy"]);
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Circle;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Square;
+///
+/// // `all()` is always on
+/// disjoint!(Circle, #[cfg(all())] Square);
+///
+/// assert_eq!(<Circle as Disjoint>::NAMES, ["Circle", "Square"]);
+/// ```
+///
+/// # Example with generic groups
+/// ```
+/// # use bevy::prelude::{Component, World};
+/// # use std::marker::PhantomData;
+/// use bevy_djqf::{Disjoint, disjoint};
+///
+/// #[derive(Debug, Default)]
+/// struct Red;
+///
+/// #[derive(Debug, Default)]
+/// struct Blue;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Leader<T: Send + Sync + 'static>(PhantomData<T>);
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Follower<T: Send + Sync + 'static>(PhantomData<T>);
+///
+/// // one group for each team
+/// disjoint!(<T: Send + Sync + 'static> Leader<T>, Follower<T>);
+///
+/// let mut world = World::new();
+/// world.spawn(Leader::<Red>::default());
+/// world.spawn(Follower::<Red>::default());
+/// world.spawn((Leader::<Blue>::default(), Follower::<Red>::default()));
+///
+/// let mut red_leaders = world.query_filtered::<(), <Leader<Red> as Disjoint>::Only>();
+/// assert_eq!(red_leaders.iter(&world).count(), 1);
+///
+/// let mut blue_leaders = world.query_filtered::<(), <Leader<Blue> as Disjoint>::Only>();
+/// assert_eq!(blue_leaders.iter(&world).count(), 1);
+///
+/// assert_eq!(<Follower<Blue> as Disjoint>::INDEX, 1);
+/// ```
+///
+/// # Example with a marker for the group
+/// ```
+/// # use bevy::prelude::{Component, Entity, World};
+/// use bevy_djqf::{Disjoint, disjoint};
+///
+/// #[derive(Component, Debug, Default)]
+/// struct GameObject;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Player;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Enemy;
+///
+/// disjoint!(in GameObject; Player, Enemy);
+///
+/// let mut world = World::new();
+/// let player = world.spawn((GameObject, Player)).id();
+/// let untagged = world.spawn(GameObject).id();
+/// // without `GameObject`, these don't match any of the filters
+/// world.spawn(Player);
+/// world.spawn_empty();
+///
+/// let mut only = world.query_filtered::<Entity, <Player as Disjoint>::Only>();
+/// assert_eq!(only.iter(&world).collect::<Vec<_>>(), [player]);
+///
+/// let mut any = world.query_filtered::<Entity, <Player as Disjoint>::Any>();
+/// assert_eq!(any.iter(&world).collect::<Vec<_>>(), [player]);
+///
+/// let mut other = world.query_filtered::<Entity, <Enemy as Disjoint>::Other>();
+/// assert_eq!(other.iter(&world).collect::<Vec<_>>(), [player]);
+///
+/// let mut none = world.query_filtered::<Entity, <Player as Disjoint>::None>();
+/// assert_eq!(none.iter(&world).collect::<Vec<_>>(), [untagged]);
+/// ```
+///
+/// # Example with a crate path
+/// ```
+/// # use bevy::prelude::{App, Update, Query, Transform};
+/// mod my_bevy {
+///     pub use bevy::ecs;
+/// }
+///
+/// use my_bevy::ecs::component::Component;
+/// use bevy_djqf::{Disjoint, disjoint};
+///
+/// #[derive(Component, Debug, Default)]
+/// struct A;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct B;
+///
+/// disjoint!(crate_path = my_bevy::ecs; A, B);
+///
+/// fn only_a(_query: Query<&mut Transform, <A as Disjoint>::Only>) {}
+///
+/// # App::new().add_systems(Update, only_a);
+/// ```
+#[macro_export]
+macro_rules! disjoint {
+    // entry point: a marker required for the whole group, with an ECS crate path
+    ( crate_path = $($ecs:ident)::+ ; in $required:ty ; $current:ty, $( $rest:ty ),+ $(,)? ) => {
+        $crate::disjoint!(@group [ $($ecs)::+ ] [] [] [ $required ] [] $current; $current, $( $rest ),+);
+    };
+
+    // entry point: a marker required for the whole group, with the default ECS crate path
+    ( in $required:ty ; $($rest:tt)* ) => {
+        $crate::disjoint!(crate_path = $crate::__private; in $required; $($rest)*);
+    };
+
+    // entry point: 2+ generic types, with an ECS crate path
+    ( crate_path = $($ecs:ident)::+ ; < $( $param:ident $( : $bound:tt $( + $bounds:tt )* )? ),+ $(,)? > $current:ty, $( $rest:ty ),+ $(,)? $( where $($where:tt)* )? ) => {
+        $crate::disjoint!(
+            @group [ $($ecs)::+ ] [ $( $param ),+ ] [ $( $( $param : $bound $( + $bounds )* , )? )+ $( $($where)* )? ] [] [] $current; $current, $( $rest ),+
+        );
+    };
+
+    // entry point: 0 or 1 generic types, with an ECS crate path
+    ( crate_path = $($ecs:ident)::+ ; < $( $param:ident $( : $bound:tt $( + $bounds:tt )* )? ),+ $(,)? > $($rest:tt)* ) => {
+        ::core::compile_error!("You must provide at least two types to `disjoint!`");
+    };
+
+    // entry point: generic types, with the default ECS crate path
+    ( < $($generic:tt)* ) => {
+        $crate::disjoint!(crate_path = $crate::__private; < $($generic)*);
+    };
+
+    // entry point: `cfg`-gated types, with an ECS crate path
+    ( crate_path = $($ecs:ident)::+ ; $( $before:ty , )* #[cfg($predicate:meta)] $($rest:tt)* ) => {
+        $crate::disjoint!(@cfg [ $($ecs)::+ ] [] [ $( $before , )* ] #[cfg($predicate)] $($rest)*);
+    };
+
+    // entry point: `cfg`-gated types, with the default ECS crate path
+    ( $( $before:ty , )* #[cfg($predicate:meta)] $($rest:tt)* ) => {
+        $crate::disjoint!(crate_path = $crate::__private; $( $before , )* #[cfg($predicate)] $($rest)*);
+    };
+
+    // a `cfg`-gated type: one invocation with the type, and one without it, for the opposite `cfg`
+    (@cfg [ $($ecs:ident)::+ ] [ $( $predicates:meta ),* ] [ $( $types:ty , )* ] #[cfg($predicate:meta)] $current:ty $( , $($rest:tt)* )?) => {
+        $crate::disjoint!(@cfg [ $($ecs)::+ ] [ $( $predicates , )* $predicate ] [ $( $types , )* $current , ] $( $($rest)* )?);
+        $crate::disjoint!(@cfg [ $($ecs)::+ ] [ $( $predicates , )* not($predicate) ] [ $( $types , )* ] $( $($rest)* )?);
+    };
+
+    (@cfg [ $($ecs:ident)::+ ] $predicates:tt [ $( $types:ty , )* ] $current:ty $( , $($rest:tt)* )?) => {
+        $crate::disjoint!(@cfg [ $($ecs)::+ ] $predicates [ $( $types , )* $current , ] $( $($rest)* )?);
+    };
+
+    (@cfg [ $($ecs:ident)::+ ] [ $( $predicates:meta ),* ] [ $( $types:ty , )* ]) => {
+        #[cfg(all( $( $predicates ),* ))]
+        $crate::disjoint!(crate_path = $($ecs)::+ ; $( $types ),*);
+    };
+
+    // entry point: more than one default, with an ECS crate path
+    ( crate_path = $($ecs:ident)::+ ; $( $before:ty , )* #[default] $default:ty $( , $between:ty )* , #[default] $($rest:tt)* ) => {
+        ::core::compile_error!("Only one type can be marked `#[default]` in `disjoint!`");
+    };
+
+    // entry point: the first type is the default, with an ECS crate path
+    ( crate_path = $($ecs:ident)::+ ; #[default] $current:ty $( , $rest:ty )* $(,)? ) => {
+        $crate::disjoint!(crate_path = $($ecs)::+ ; $current $( , $rest )*);
+
+        #[allow(deprecated)]
+        impl $crate::DefaultVariant for $current {
+            type Default = $current;
+        }
+    };
+
+    // entry point: a later type is the default, with an ECS crate path
+    ( crate_path = $($ecs:ident)::+ ; $current:ty, $( $before:ty , )* #[default] $default:ty $( , $after:ty )* $(,)? ) => {
+        $crate::disjoint!(crate_path = $($ecs)::+ ; $current, $( $before , )* $default $( , $after )*);
+
+        #[allow(deprecated)]
+        impl $crate::DefaultVariant for $current {
+            type Default = $default;
+        }
+    };
+
+    // entry point: a default, with the default ECS crate path
+    ( $( $before:ty , )* #[default] $($rest:tt)* ) => {
+        $crate::disjoint!(crate_path = $crate::__private; $( $before , )* #[default] $($rest)*);
+    };
+
+    // entry point: 0 or 1 types, with an ECS crate path
+    ( crate_path = $($ecs:ident)::+ ; $( $current:ty )? $(,)? ) => {
+        ::core::compile_error!("You must provide at least two types to `disjoint!`");
+    };
+
+    // entry point: 1 type
+    ( $current:ty $(,)? ) => {
+        ::core::compile_error!("You must provide at least two types to `disjoint!`");
+    };
+
+    // entry point: 0 types
+    () => {
+        ::core::compile_error!("You must provide at least two types to `disjoint!`");
+    };
+
+    // entry point: 2+ types, with the default ECS crate path
+    ( $current:ty, $( $rest:ty ),+ $(,)? ) => {
+        $crate::disjoint!(crate_path = $crate::__private; $current, $( $rest ),+);
+    };
+
+    // entry point: 2+ types, with an ECS crate path
+    ( crate_path = $($ecs:ident)::+ ; $current:ty, $( $rest:ty ),+ $(,)? ) => {
+        $crate::disjoint!(@group [ $($ecs)::+ ] [] [] [] [] $current; $current, $( $rest ),+);
+    };
+
+    // entry point: 2+ types with explicit indices, with an ECS crate path
+    ( crate_path = $($ecs:ident)::+ ; $current:ty = $current_index:expr, $( $rest:ty = $index:expr ),+ $(,)? ) => {
+        $crate::disjoint!(@group [ $($ecs)::+ ] [] [] [] [ $current_index $( , $index )+ ] $current; $current, $( $rest ),+);
+    };
+
+    // entry point: explicit indices, with the default ECS crate path
+    ( $current:ty = $($rest:tt)* ) => {
+        $crate::disjoint!(crate_path = $crate::__private; $current = $($rest)*);
+    };
+
+    // the generic parameters and the where clause are captured as single groups, so they can be repeated for each type
+    (@group [ $($ecs:ident)::+ ] [ $( $param:ident ),* ] [ $($predicate:tt)* ] $required:tt $indices:tt $group:ty; $current:ty, $( $rest:ty ),+) => {
+        $crate::__private::disjoint_unique!($current, $( $rest ),+);
+
+        // the group is represented by the first type, or by the struct from `disjoint_groups!`
+        #[allow(deprecated)]
+        impl< $( $param ),* > $crate::DisjointGroup for $group where $($predicate)* {
+            type Members = ( $current , $( $rest , )* );
+
+            const COUNT: usize = $crate::disjoint!(@count $indices [ $current , $( $rest , )* ]);
+
+            type Array<__T> = [__T; $crate::disjoint!(@count $indices [ $current , $( $rest , )* ])];
+
+            const NAMES: &'static [&'static str] = $crate::disjoint!(@names $indices $current $( , $rest )*);
+
+            $crate::__private::register_reflected!();
+
+            // the type parameter isn't hygienic, so it mustn't shadow a member named e.g. `V`
+            fn for_each_member<__V: $crate::MemberVisitor>(visitor: &mut __V) {
+                $crate::disjoint!(@visit $indices visitor; $current $( , $rest )*);
+            }
+        }
+
+        // higher-ranked bounds, so that a group with a member that doesn't implement `Default` still compiles,
+        // and the impl just doesn't apply
+        #[allow(deprecated)]
+        impl< $( $param ),* > $crate::DefaultMembers for $group
+        where
+            for<'__a> $current: ::core::default::Default,
+            $( for<'__a> $rest: ::core::default::Default, )+
+            $($predicate)*
+        {
+            fn insert(entity: &mut $crate::__private::EntityWorldMut<'_>, index: usize) -> bool {
+                if <$current as $crate::Disjoint>::INDEX == index {
+                    entity.insert(<$current as ::core::default::Default>::default());
+                    return true;
+                }
+                $(
+                    if <$rest as $crate::Disjoint>::INDEX == index {
+                        entity.insert(<$rest as ::core::default::Default>::default());
+                        return true;
+                    }
+                )+
+                false
+            }
+        }
+
+        $crate::__private::reflect_members!([ $( $param ),* ] [ $($predicate)* ] $group; $current, $( $rest ),+);
+
+        $crate::disjoint!(@check $indices);
+
+        $crate::disjoint!(@for [ $($ecs)::+ ] [ $( $param ),* ] [ $($predicate)* ] $required $indices $group; [] $current [ $( $rest , )* ]);
+    };
+
+    // 4+ remaining: 4 impls at a time, so that large groups don't reach the recursion limit
+    (@for [ $($ecs:ident)::+ ] $params:tt $predicates:tt $required:tt $indices:tt $group:ty; [ $( $consumed:ty , )* ] $c0:ty [ $c1:ty , $c2:ty , $c3:ty , $next:ty , $( $later:ty , )* ]) => {
+        $crate::disjoint!(@imp [ $($ecs)::+ ] $params $predicates $required $indices $group; [ $( $consumed , )* ] $c0 [ $c1 , $c2 , $c3 , $next , $( $later , )* ]);
+        $crate::disjoint!(@imp [ $($ecs)::+ ] $params $predicates $required $indices $group; [ $( $consumed , )* $c0 , ] $c1 [ $c2 , $c3 , $next , $( $later , )* ]);
+        $crate::disjoint!(@imp [ $($ecs)::+ ] $params $predicates $required $indices $group; [ $( $consumed , )* $c0 , $c1 , ] $c2 [ $c3 , $next , $( $later , )* ]);
+        $crate::disjoint!(@imp [ $($ecs)::+ ] $params $predicates $required $indices $group; [ $( $consumed , )* $c0 , $c1 , $c2 , ] $c3 [ $next , $( $later , )* ]);
+        $crate::disjoint!(@for [ $($ecs)::+ ] $params $predicates $required $indices $group; [ $( $consumed , )* $c0 , $c1 , $c2 , $c3 , ] $next [ $( $later , )* ]);
+    };
+
+    // 2+ remaining
+    (@for [ $($ecs:ident)::+ ] $params:tt $predicates:tt $required:tt $indices:tt $group:ty; [ $( $consumed:ty , )* ] $current:ty [ $next:ty , $( $later:ty , )* ]) => {
+        $crate::disjoint!(@imp [ $($ecs)::+ ] $params $predicates $required $indices $group; [ $( $consumed , )* ] $current [ $next , $( $later , )* ]);
+        $crate::disjoint!(@for [ $($ecs)::+ ] $params $predicates $required $indices $group; [ $( $consumed , )* $current , ] $next [ $( $later , )* ]);
+    };
+
+    // 1 remaining
+    (@for [ $($ecs:ident)::+ ] $params:tt $predicates:tt $required:tt $indices:tt $group:ty; [ $( $consumed:ty , )* ] $current:ty [ $next:ty ]) => {
+        $crate::disjoint!(@imp [ $($ecs)::+ ] $params $predicates $required $indices $group; [ $( $consumed , )* ] $current [ $next ]);
+        $crate::disjoint!(@for [ $($ecs)::+ ] $params $predicates $required $indices $group; [ $( $consumed , )* $current , ] $next []);
+    };
+
+    // 0 remaining
+    (@for [ $($ecs:ident)::+ ] $params:tt $predicates:tt $required:tt $indices:tt $group:ty; [ $( $consumed:ty , )* ] $current:ty []) => {
+        $crate::disjoint!(@imp [ $($ecs)::+ ] $params $predicates $required $indices $group; [ $( $consumed , )* ] $current []);
+    };
+
+    (@imp [ $($ecs:ident)::+ ] [ $( $param:ident ),* ] [ $($predicate:tt)* ] $required:tt $indices:tt $group:ty; [ $( $before:ty , )* ] $current:ty [ $( $after:ty , )* ]) => {
+        // members can be `#[deprecated]` but still part of the group, e.g. so that old saves load
+        #[allow(deprecated)]
+        const _: () = {
+            // the ECS crate path can't be repeated along with the types, so import the filters once
+            use $($ecs)::+::query::{Or as __Or, With as __With, Without as __Without};
+
+            impl< $( $param ),* > $crate::Disjoint for $current where $($predicate)* {
+
+                type Group = $group;
+
+                const COUNT: usize = $crate::disjoint!(@count $indices [ $( $before , )* $current , $( $after , )* ]);
+
+                const INDEX: usize = $crate::disjoint!(@index $indices [ $( $before , )* ]);
+
+                const NAME: &'static str = ::core::stringify!($current);
+
+                const NAMES: &'static [&'static str] = <$group as $crate::DisjointGroup>::NAMES;
+
+                type Any = $crate::disjoint!(@in $required $crate::disjoint!(@or
+                    $(__With<$before> , )*
+                    __With<$current> ,
+                    $(__With<$after> , )*
+                ));
+
+                type Other = $crate::disjoint!(@in $required (
+                    __Without<$current> ,
+                    $crate::disjoint!(@or
+                        $(__With<$before> , )*
+                        $(__With<$after> , )*
+                    )
+                ));
+
+                type Only = $crate::disjoint!(@in $required $crate::disjoint!(@and
+                    $(__Without<$before> , )*
+                    __With<$current> ,
+                    $(__Without<$after> , )*
+                ));
+
+                type Not = $crate::disjoint!(@in $required __Without<$current>);
+
+                type None = $crate::disjoint!(@in $required $crate::disjoint!(@and
+                    $(__Without<$before> , )*
+                    __Without<$current> ,
+                    $(__Without<$after> , )*
+                ));
+
+            }
+
+            impl< $( $param ),* > $crate::__private::SubsetMember<$group> for $current where $($predicate)* {
+                type Only = <$current as $crate::Disjoint>::Only;
+
+                type Without = __Without<$current>;
+
+                type Mask = $crate::disjoint!(@cons [ $crate::disjoint!(@required $required) ]
+                    $( $crate::__private::Excluded<$before> , )*
+                    $crate::__private::Included<$current> ,
+                    $( $crate::__private::Excluded<$after> , )*
+                );
+            }
+        };
+    };
+
+    // a filter, also requiring the marker provided with `in`, if any
+    (@in [] $filter:ty) => {
+        $filter
+    };
+
+    (@in [ $required:ty ] $filter:ty) => {
+        ( $filter , __With<$required> )
+    };
+
+    // the end of a mask, with the marker provided with `in`, if any
+    (@required []) => {
+        ()
+    };
+
+    (@required [ $required:ty ]) => {
+        ( $crate::__private::Required<$required> , () )
+    };
+
+    // a list of types nested in pairs, 4 at a time so that large groups don't reach the recursion limit
+    (@cons [ $end:ty ] $t0:ty, $t1:ty, $t2:ty, $t3:ty, $( $rest:ty , )+) => {
+        ( $t0 , ( $t1 , ( $t2 , ( $t3 , $crate::disjoint!(@cons [ $end ] $( $rest , )+) ) ) ) )
+    };
+
+    (@cons [ $end:ty ] $t:ty, $( $rest:ty , )*) => {
+        ( $t , $crate::disjoint!(@cons [ $end ] $( $rest , )*) )
+    };
+
+    (@cons [ $end:ty ]) => {
+        $end
+    };
+
+    // counts a type
+    (@one $t:ty) => {
+        1
+    };
+
+    // the number of types, or one more than the largest index given
+    (@count [] [ $( $t:ty , )+ ]) => {
+        0 $( + $crate::disjoint!(@one $t) )+
+    };
+
+    (@count [ $( $index:expr ),+ ] $types:tt) => {
+        $crate::__private::count_indices(&[ $( $index ),+ ])
+    };
+
+    // the index of a type is its position, or the index given for it
+    (@index [] [ $( $before:ty , )* ]) => {
+        0 $( + $crate::disjoint!(@one $before) )*
+    };
+
+    (@index [ $index:expr $( , $later:expr )* ] []) => {
+        $index
+    };
+
+    (@index [ $skipped:expr $( , $later:expr )* ] [ $skip:ty , $( $before:ty , )* ]) => {
+        $crate::disjoint!(@index [ $( $later ),* ] [ $( $before , )* ])
+    };
+
+    // the names and the visits are in the order of the indices, so that the position of a member is its index,
+    // with an empty name for an index without a member
+    (@names [] $( $t:ty ),+) => {
+        &[ $( ::core::stringify!($t) , )+ ]
+    };
+
+    (@names [ $( $index:expr ),+ ] $( $t:ty ),+) => {
+        &{
+            let mut names = [""; $crate::__private::count_indices(&[ $( $index ),+ ])];
+            $( names[$index] = ::core::stringify!($t); )+
+            names
+        }
+    };
+
+    (@visit [] $visitor:ident; $( $t:ty ),+) => {
+        $( $visitor.visit::<$t>(); )+
+    };
+
+    (@visit [ $( $index:expr ),+ ] $visitor:ident; $( $t:ty ),+) => {
+        for index in 0..$crate::__private::count_indices(&[ $( $index ),+ ]) {
+            $(
+                if index == <$t as $crate::Disjoint>::INDEX {
+                    $visitor.visit::<$t>();
+                }
+            )+
+        }
+    };
+
+    (@check []) => {};
+
+    (@check [ $( $index:expr ),+ ]) => {
+        const _: () = $crate::__private::check_indices(&[ $( $index ),+ ]);
+    };
+
+    // query filters are only implemented for tuples of up to 15 filters,
+    // so larger groups nest the rest of the filters in the last position, which keeps smaller groups flat
+    (@and $f0:ty, $f1:ty, $f2:ty, $f3:ty, $f4:ty, $f5:ty, $f6:ty, $f7:ty, $f8:ty, $f9:ty, $f10:ty, $f11:ty, $f12:ty, $f13:ty, $f14:ty, $( $rest:ty , )+) => {
+        ( $f0, $f1, $f2, $f3, $f4, $f5, $f6, $f7, $f8, $f9, $f10, $f11, $f12, $f13, $crate::disjoint!(@and $f14, $( $rest , )+) )
+    };
+
+    (@and $( $filter:ty , )*) => {
+        ( $( $filter , )* )
+    };
+
+    (@or $f0:ty, $f1:ty, $f2:ty, $f3:ty, $f4:ty, $f5:ty, $f6:ty, $f7:ty, $f8:ty, $f9:ty, $f10:ty, $f11:ty, $f12:ty, $f13:ty, $f14:ty, $( $rest:ty , )+) => {
+        __Or<( $f0, $f1, $f2, $f3, $f4, $f5, $f6, $f7, $f8, $f9, $f10, $f11, $f12, $f13, $crate::disjoint!(@or $f14, $( $rest , )+) )>
+    };
+
+    // an `Or` of one filter is that filter, e.g. for `Other` in a group of 2
+    (@or $filter:ty ,) => {
+        $filter
+    };
+
+    (@or $( $filter:ty , )*) => {
+        __Or<( $( $filter , )* )>
+    };
+
+    ( $($invalid_input:tt)* ) => {
+        ::core::compile_error!(
+            ::core::concat!(
+                "Invalid input `",
+                ::core::stringify!($($invalid_input)*),
+                "` to macro `disjoint!`. Use the form `disjoint!(A, B)`, `disjoint!(in Tag; A, B)` or `disjoint!(crate_path = bevy_ecs; A, B)`"
+            )
+        );
+    };
+}
+
+/// Generate several independent groups of disjoint query filters in one invocation.
+///
+/// `disjoint_groups! { Faction { Red, Blue }, Shape { Circle, Square } }` is the same as
+/// `disjoint!(Red, Blue); disjoint!(Circle, Square);`, so each group's filters only refer to its own members,
+/// and also names each group with a type alias for it, e.g. `type Faction = Red;`,
+/// for use wherever a [`DisjointGroup`] is expected. The alias can be given a visibility, e.g. `pub Faction { Red, Blue }`.
+///
+/// With `struct Faction { Red, Blue }`, the group is instead a new zero-sized struct, `struct Faction;`,
+/// which implements [`DisjointGroup`] and is the [`Group`](Disjoint::Group) of every member,
+/// so the first member doesn't stand for the group: `PerVariant<Faction, T>` and `DisjointCheckPlugin::<Faction>`
+/// work, but `PerVariant<Red, T>` doesn't. As with the alias, the name is up to you, so it can avoid existing types.
+///
+/// # Example
+/// ```
+/// # use std::marker::PhantomData;
+/// # use bevy::prelude::{Component, Or, With, Without, World};
+/// use bevy_djqf::{disjoint_groups, Disjoint, DisjointGroup};
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Red;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Blue;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Circle;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Square;
+///
+/// disjoint_groups! {
+///     Faction { Red, Blue },
+///     Shape { Circle, Square },
+/// }
+///
+/// fn same_type<T>(_: PhantomData<T>, _: PhantomData<T>) {}
+///
+/// // `Other` only refers to members of the same group
+/// same_type(
+///     PhantomData::<<Red as Disjoint>::Other>,
+///     PhantomData::<(Without<Red>, With<Blue>)>,
+/// );
+/// assert_eq!(<Faction as DisjointGroup>::NAMES, ["Red", "Blue"]);
+/// assert_eq!(<Shape as DisjointGroup>::NAMES, ["Circle", "Square"]);
+///
+/// // an entity can have one "variant" of each group
+/// let mut world = World::new();
+/// world.spawn((Red, Circle));
+/// let mut red = world.query_filtered::<(), <Red as Disjoint>::Only>();
+/// let mut circle = world.query_filtered::<(), <Circle as Disjoint>::Only>();
+/// assert_eq!(red.iter(&world).count(), 1);
+/// assert_eq!(circle.iter(&world).count(), 1);
+/// ```
+///
+/// # Example with a struct for the group
+/// ```
+/// # use bevy::prelude::{App, Component};
+/// use bevy_djqf::{disjoint_groups, Disjoint, DisjointEnforcePlugin, DisjointGroup, PerVariant};
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Player;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Enemy;
+///
+/// disjoint_groups! {
+///     struct GameObject { Player, Enemy },
+/// }
+///
+/// assert_eq!(std::mem::size_of::<GameObject>(), 0);
+/// assert_eq!(<GameObject as DisjointGroup>::NAMES, ["Player", "Enemy"]);
+///
+/// fn same_type<T>(_: T, _: T) {}
+/// same_type(<Enemy as Disjoint>::Group::default(), GameObject);
+///
+/// let speeds = PerVariant::<GameObject, f32>::from_fn(|index| index as f32);
+/// assert_eq!(*speeds.get::<Enemy>(), 1.0);
+///
+/// let mut app = App::new();
+/// app.add_plugins(DisjointEnforcePlugin::<GameObject>::default());
+/// app.world_mut().spawn(Player);
+/// app.update();
+/// ```
+#[macro_export]
+macro_rules! disjoint_groups {
+    () => {};
+
+    // a struct for the group
+    ( $vis:vis struct $group:ident { $current:ty, $( $rest:ty ),+ $(,)? } $(, $($groups:tt)* )? ) => {
+        /// A disjoint group, generated by `disjoint_groups!`.
+        #[derive(
+            ::core::clone::Clone,
+            ::core::marker::Copy,
+            ::core::fmt::Debug,
+            ::core::default::Default,
+            ::core::cmp::PartialEq,
+            ::core::cmp::Eq,
+            ::core::hash::Hash,
+        )]
+        $vis struct $group;
+
+        $crate::disjoint!(@group [ $crate::__private ] [] [] [] [] $group; $current, $( $rest ),+);
+
+        $crate::disjoint_groups!($( $($groups)* )?);
+    };
+
+    // an alias of the first member for the group
+    ( $vis:vis $group:ident { $first:ty $(, $rest:ty )* $(,)? } $(, $($groups:tt)* )? ) => {
+        $vis type $group = $first;
+
+        $crate::disjoint!($first $(, $rest )*);
+
+        $crate::disjoint_groups!($( $($groups)* )?);
+    };
+}
+
+/// Generate disjoint query filters for a tree of "variants", where each "variant" at the top level has its own sub-"variants".
+///
+/// `disjoint_tree! { Player { FriendlyPlayer, EnemyPlayer }, Npc { FriendlyAi, EnemyAi } }` makes the parents disjoint,
+/// as with `disjoint!(Player, Npc)`, and makes every leaf disjoint from every other leaf, as with
+/// `disjoint!(FriendlyPlayer, EnemyPlayer, FriendlyAi, EnemyAi)`. Each leaf implements [`Subvariant`] with its parent.
+///
+/// The parents are your own components, which must implement [`Default`]. The leaves are unit structs generated by this macro,
+/// which can be given attributes and a visibility, e.g. `Player { #[derive(Reflect)] pub FriendlyPlayer, pub EnemyPlayer }`.
+///
+/// A leaf implies its parent: when a leaf is added to an entity, e.g. by spawning it directly, its parent is set too,
+/// replacing any other parent, so that `<Player as Disjoint>::Only` matches both friendly and enemy players, but no NPCs.
+/// This is done with commands from a component hook, so it is applied along with the next commands, e.g. on the next `World::flush`.
+/// [`DisjointEntityCommandsExt::set_subvariant`] sets both levels at once, and also removes the other leaves.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::{Commands, Component, Entity, World};
+/// # use bevy::ecs::world::CommandQueue;
+/// use bevy_djqf::{disjoint_tree, Disjoint, DisjointEntityCommandsExt};
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Player;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Npc;
+///
+/// disjoint_tree! {
+///     Player { FriendlyPlayer, EnemyPlayer },
+///     Npc { FriendlyAi, EnemyAi },
+/// }
+///
+/// let mut world = World::new();
+/// let friend = world.spawn_empty().id();
+/// let enemy = world.spawn_empty().id();
+/// // the parent is added for the leaf
+/// let npc = world.spawn(FriendlyAi).id();
+///
+/// let mut queue = CommandQueue::default();
+/// let mut commands = Commands::new(&mut queue, &world);
+/// commands.entity(friend).set_subvariant::<FriendlyPlayer>();
+/// commands.entity(enemy).set_subvariant::<EnemyPlayer>();
+/// queue.apply(&mut world);
+/// world.flush();
+///
+/// // the parent level
+/// let mut players = world.query_filtered::<Entity, <Player as Disjoint>::Only>();
+/// let mut players = players.iter(&world).collect::<Vec<_>>();
+/// players.sort();
+/// assert_eq!(players, [friend, enemy]);
+/// assert!(world.entity(npc).contains::<Npc>());
+///
+/// // the leaf level
+/// let mut friendly = world.query_filtered::<Entity, <FriendlyPlayer as Disjoint>::Only>();
+/// assert_eq!(friendly.iter(&world).collect::<Vec<_>>(), [friend]);
+/// let mut not_friendly = world.query_filtered::<Entity, <FriendlyPlayer as Disjoint>::Other>();
+/// assert_eq!(not_friendly.iter(&world).count(), 2);
+/// ```
+#[macro_export]
+macro_rules! disjoint_tree {
+    ( $( $parent:ty { $( $(#[$attr:meta])* $vis:vis $leaf:ident ),+ $(,)? } ),+ $(,)? ) => {
+        $crate::disjoint!($( $parent ),+);
+
+        $crate::disjoint!($( $( $leaf ),+ ),+);
+
+        $( $(
+            $(#[$attr])*
+            #[derive(
+                ::core::clone::Clone,
+                ::core::marker::Copy,
+                ::core::fmt::Debug,
+                ::core::default::Default,
+                ::core::cmp::PartialEq,
+                ::core::cmp::Eq,
+                ::core::hash::Hash,
+            )]
+            $vis struct $leaf;
+
+            // the parent is set from a hook, so that it is also set when the leaf is spawned directly
+            impl $crate::__private::Component for $leaf {
+                const STORAGE_TYPE: $crate::__private::StorageType = $crate::__private::StorageType::Table;
+
+                fn register_component_hooks(hooks: &mut $crate::__private::ComponentHooks) {
+                    hooks.on_add(|mut world, entity, _| {
+                        if !world.entity(entity).contains::<$parent>() {
+                            $crate::DisjointEntityCommandsExt::set_variant::<$parent>(
+                                &mut world.commands().entity(entity),
+                            );
+                        }
+                    });
+                }
+            }
+
+            impl $crate::Subvariant for $leaf {
+                type Parent = $parent;
+            }
+        )+ )+
+    };
+}
+
+/// Generate disjoint query filters for the provided list of types, along with a `const` array of metadata
+/// with one value for each "variant", e.g. a debug color, which can be looked up by [`INDEX`](Disjoint::INDEX).
+///
+/// `disjoint_with_meta! { const META: T; A => a, B => b }` is the same as `disjoint!(A, B)`,
+/// and `const META: [T; 2] = [a, b];`. Each value must be a constant expression of the type `T`.
+/// Attributes and a visibility can be given before the `const`.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::{Color, Component};
+/// use bevy_djqf::{disjoint_with_meta, Disjoint};
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Player;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Enemy;
+///
+/// disjoint_with_meta! {
+///     /// The color of the debug overlay of each "variant".
+///     pub const DEBUG_COLOR: Color;
+///     Player => Color::srgb(0.0, 1.0, 0.0),
+///     Enemy => Color::srgb(1.0, 0.0, 0.0),
+/// }
+///
+/// assert_eq!(DEBUG_COLOR.len(), <Player as Disjoint>::COUNT);
+/// assert_eq!(DEBUG_COLOR[Enemy::INDEX], Color::srgb(1.0, 0.0, 0.0));
+/// ```
+#[macro_export]
+macro_rules! disjoint_with_meta {
+    ( $(#[$attr:meta])* $vis:vis const $meta:ident : $meta_ty:ty ; $first:ty => $first_value:expr $(, $rest:ty => $value:expr )* $(,)? ) => {
+        $crate::disjoint!($first $(, $rest )*);
+
+        $(#[$attr])*
+        #[allow(deprecated)]
+        $vis const $meta: [$meta_ty; <$first as $crate::DisjointGroup>::COUNT] = [$first_value $(, $value )*];
+    };
+}
+
+/// Generate a query filter for entities with any of the provided "variants", and none of the other members of their group.
+///
+/// With `disjoint!(A, B, C, D)`, `disjoint_subset!(A, B)` is equivalent to `(Or<(With<A>, With<B>)>, Without<C>, Without<D>)`,
+/// so it matches entities with `A` or `B`, or both, but without `C` or `D`.
+/// The other members are taken from the group, so the filter doesn't need to change when members are added.
+/// For entities with exactly one of the provided "variants", use [`disjoint_exactly_one!`].
+///
+/// All of the "variants" must be members of the same group, i.e. provided to the same [`disjoint!`] macro.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::{Component, With, World};
+/// use bevy_djqf::{disjoint, disjoint_subset};
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Player;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct FriendlyAi;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct EnemyAi;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct InanimateObject;
+///
+/// disjoint!(Player, FriendlyAi, EnemyAi, InanimateObject);
+///
+/// type Allies = disjoint_subset!(Player, FriendlyAi);
+///
+/// let mut world = World::new();
+/// let player = world.spawn(Player).id();
+/// let friend = world.spawn(FriendlyAi).id();
+/// world.spawn(EnemyAi);
+/// world.spawn(InanimateObject);
+/// world.spawn_empty();
+///
+/// let mut allies = world
+///     .query_filtered::<bevy::prelude::Entity, Allies>()
+///     .iter(&world)
+///     .collect::<Vec<_>>();
+/// allies.sort();
+/// assert_eq!(allies, [player, friend]);
+/// ```
+///
+/// "Variants" from different groups are rejected:
+/// ```compile_fail
+/// # use bevy::prelude::Component;
+/// use bevy_djqf::{disjoint, disjoint_subset};
+///
+/// #[derive(Component, Debug, Default)]
+/// struct A;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct B;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct X;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Y;
+///
+/// disjoint!(A, B);
+/// disjoint!(X, Y);
+///
+/// type Mixed = disjoint_subset!(A, X);
+///
+/// let _: Option<bevy::prelude::Query<(), Mixed>> = None;
+/// ```
+#[macro_export]
+macro_rules! disjoint_subset {
+    // the masks of the "variants" are combined one at a time
+    (@union $group:ty; $mask:ty;) => {
+        $mask
+    };
+
+    (@union $group:ty; $mask:ty; $next:ty $(, $rest:ty )*) => {
+        $crate::disjoint_subset!(@union $group;
+            <$mask as $crate::__private::Union<<$next as $crate::__private::SubsetMember<$group>>::Mask>>::Output;
+            $( $rest ),*
+        )
+    };
+
+    ( $first:ty $(, $rest:ty )* $(,)? ) => {
+        (
+            $crate::__private::query::Or<(
+                $crate::__private::query::With<$first>,
+                $( $crate::__private::query::With<$rest>, )*
+            )>,
+            <$crate::disjoint_subset!(@union <$first as $crate::Disjoint>::Group;
+                <$first as $crate::__private::SubsetMember<<$first as $crate::Disjoint>::Group>>::Mask;
+                $( $rest ),*
+            ) as $crate::__private::MaskFilter>::Filter,
+        )
+    };
+}
+
+/// Generate a query filter for entities with exactly one of the provided "variants", and none of the other members of their group.
+///
+/// `disjoint_exactly_one!(A, B)` is equivalent to `Or<(<A as Disjoint>::Only, <B as Disjoint>::Only)>`:
+/// each [`Only`](Disjoint::Only) filter excludes every other member of the group, including the other provided "variants",
+/// so an entity with both `A` and `B` matches neither.
+///
+/// All of the "variants" must be members of the same group, i.e. provided to the same [`disjoint!`] macro.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::{Component, Entity, World};
+/// use bevy_djqf::{disjoint, disjoint_exactly_one};
+///
+/// #[derive(Component, Debug, Default)]
+/// struct A;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct B;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct C;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct D;
+///
+/// disjoint!(A, B, C, D);
+///
+/// type AOrB = disjoint_exactly_one!(A, B);
+///
+/// let mut world = World::new();
+/// let a = world.spawn(A).id();
+/// let b = world.spawn(B).id();
+/// world.spawn(C);
+/// world.spawn(D);
+/// world.spawn_empty();
+/// // more than one of the subset
+/// world.spawn((A, B));
+/// // one of the subset, and one from outside it
+/// world.spawn((A, C));
+/// world.spawn((B, D));
+///
+/// let mut matching = world
+///     .query_filtered::<Entity, AOrB>()
+///     .iter(&world)
+///     .collect::<Vec<_>>();
+/// matching.sort();
+/// assert_eq!(matching, [a, b]);
+/// ```
+///
+/// As with [`disjoint_subset!`], "variants" from different groups are rejected:
+/// ```compile_fail
+/// # use bevy::prelude::Component;
+/// use bevy_djqf::{disjoint, disjoint_exactly_one};
+///
+/// #[derive(Component, Debug, Default)]
+/// struct A;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct B;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct X;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Y;
+///
+/// disjoint!(A, B);
+/// disjoint!(X, Y);
+///
+/// // only the last one is from another group
+/// type Mixed = disjoint_exactly_one!(A, B, Y);
+///
+/// let _: Option<bevy::prelude::Query<(), Mixed>> = None;
+/// ```
+#[macro_export]
+macro_rules! disjoint_exactly_one {
+    ( $first:ty $(, $rest:ty )* $(,)? ) => {
+        $crate::__private::query::Or<(
+            <$first as $crate::Disjoint>::Only,
+            $( <$rest as $crate::__private::SubsetMember<<$first as $crate::Disjoint>::Group>>::Only, )*
+        )>
+    };
+}
+
+/// Generate a query filter for entities with a member of the group, but none of the provided "variants".
+///
+/// `disjoint_except!(A, B)` is equivalent to `(<A as Disjoint>::Any, Without<A>, Without<B>)`,
+/// so with `disjoint!(A, B, C, D)` it matches entities with `C` or `D`, but without `A` or `B`.
+/// This is [`Other`](Disjoint::Other) for more than one "variant".
+///
+/// All of the "variants" must be members of the same group, i.e. provided to the same [`disjoint!`] macro.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::{Component, Entity, World};
+/// use bevy_djqf::{disjoint, disjoint_except};
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Player;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct FriendlyAi;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct EnemyAi;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Wildlife;
+///
+/// disjoint!(Player, FriendlyAi, EnemyAi, Wildlife);
+///
+/// type NotAllies = disjoint_except!(Player, FriendlyAi);
+///
+/// let mut world = World::new();
+/// world.spawn(Player);
+/// world.spawn(FriendlyAi);
+/// let enemy = world.spawn(EnemyAi).id();
+/// let wildlife = world.spawn(Wildlife).id();
+/// world.spawn_empty();
+/// world.spawn((FriendlyAi, EnemyAi));
+///
+/// let mut not_allies = world
+///     .query_filtered::<Entity, NotAllies>()
+///     .iter(&world)
+///     .collect::<Vec<_>>();
+/// not_allies.sort();
+/// assert_eq!(not_allies, [enemy, wildlife]);
+/// ```
+///
+/// "Variants" from different groups are rejected:
+/// ```compile_fail
+/// # use bevy::prelude::Component;
+/// use bevy_djqf::{disjoint, disjoint_except};
+///
+/// #[derive(Component, Debug, Default)]
+/// struct A;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct B;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct X;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Y;
+///
+/// disjoint!(A, B);
+/// disjoint!(X, Y);
+///
+/// type Mixed = disjoint_except!(A, X);
+///
+/// let _: Option<bevy::prelude::Query<(), Mixed>> = None;
+/// ```
+#[macro_export]
+macro_rules! disjoint_except {
+    ( $first:ty $(, $rest:ty )* $(,)? ) => {
+        (
+            <$first as $crate::Disjoint>::Any,
+            $crate::__private::query::Without<$first>,
+            $( <$rest as $crate::__private::SubsetMember<<$first as $crate::Disjoint>::Group>>::Without, )*
+        )
+    };
+}
+
+/// Generate a [`SystemParam`](bevy_ecs::system::SystemParam) with one [`Query`](bevy_ecs::system::Query) for each of the provided "variants",
+/// each filtered by its [`Only`](Disjoint::Only) filter, so that they can all be mutable without a `ParamSet`.
+///
+/// This is [`DisjointPair`] for any number of "variants". Each field is named, and all of the queries fetch the same data:
+///
+/// `disjoint_queries! { pub struct Movers: &'static mut Transform { pub player: Player, pub enemy: Enemy } }`
+///
+/// Attributes and doc comments are kept on the struct and on each field. All of the "variants" must be members
+/// of the same group. Providing the same "variant" twice compiles, but Bevy panics when the system is added,
+/// since the two queries conflict.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::{App, Component, Transform, Update, Vec3};
+/// use bevy_djqf::{disjoint, disjoint_queries};
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Player;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Enemy;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Wall;
+///
+/// disjoint!(Player, Enemy, Wall);
+///
+/// disjoint_queries! {
+///     /// The transforms of everything that moves, or is moved.
+///     struct Movers: &'static mut Transform {
+///         player: Player,
+///         enemies: Enemy,
+///         walls: Wall,
+///     }
+/// }
+///
+/// fn movement(mut movers: Movers) {
+///     for mut player in &mut movers.player {
+///         for mut enemy in &mut movers.enemies {
+///             enemy.translation = player.translation;
+///         }
+///         for mut wall in &mut movers.walls {
+///             wall.translation -= Vec3::X;
+///         }
+///         player.translation += Vec3::X;
+///     }
+/// }
+///
+/// # App::new().add_systems(Update, movement).update();
+/// ```
+///
+/// The "variants" must be from the same group:
+/// ```compile_fail
+/// # use bevy::prelude::{Component, Transform};
+/// use bevy_djqf::{disjoint, disjoint_queries};
+///
+/// #[derive(Component, Debug, Default)]
+/// struct A;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct B;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct X;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Y;
+///
+/// disjoint!(A, B);
+/// disjoint!(X, Y);
+///
+/// disjoint_queries! {
+///     struct Mixed: &'static mut Transform {
+///         a: A,
+///         x: X,
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! disjoint_queries {
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident : $data:ty {
+            $( $(#[$field_attr:meta])* $field_vis:vis $field:ident : $member:ty ),+ $(,)?
+        }
+    ) => {
+        $(#[$attr])*
+        #[derive($crate::__private::SystemParam)]
+        $vis struct $name<'w, 's> {
+            $(
+                $(#[$field_attr])*
+                $field_vis $field: $crate::__private::Query<'w, 's, $data, <$member as $crate::Disjoint>::Only>,
+            )+
+        }
+
+        $crate::disjoint_queries!(@same_group $( $member ),+);
+    };
+
+    (@same_group $first:ty $(, $rest:ty )*) => {
+        const _: () = {
+            $( let _: fn() = $crate::__private::same_group::<$first, $rest>; )*
+        };
+    };
+}
+
+/// The [`Only`](Disjoint::Only) filter of a "variant", in type position: `only!(Player)` is `<Player as Disjoint>::Only`.
+///
+/// [`other!`](filters::other), [`any!`](filters::any), [`not!`](filters::not) and [`none!`](filters::none)
+/// are the same for the other filters. They accept any type, including generic parameters and generic markers,
+/// e.g. `only!(Leader<T>)`.
+///
+/// These are only exported from the [`filters`] module, so that `use bevy_djqf::*` doesn't bring short names
+/// like `any!` and `not!` into scope.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::{App, Component, Entity, Query, Transform, Update};
+/// use bevy_djqf::filters::{any, none, not, only, other};
+/// use bevy_djqf::{disjoint, Disjoint};
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Player;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Enemy;
+///
+/// disjoint!(Player, Enemy);
+///
+/// fn move_player(_query: Query<&mut Transform, only!(Player)>) {}
+///
+/// fn move_others(_query: Query<&mut Transform, other!(Player)>) {}
+///
+/// fn count(_any: Query<Entity, any!(Enemy)>, _not: Query<Entity, not!(Enemy)>, _none: Query<Entity, none!(Enemy)>) {}
+///
+/// // in generic code too
+/// fn move_only<V: Disjoint + 'static>(_query: Query<&mut Transform, only!(V)>) {}
+///
+/// # App::new().add_systems(Update, (move_player, move_others, count, move_only::<Enemy>));
+/// ```
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __djqf_only {
+    ( $variant:ty ) => {
+        <$variant as $crate::Disjoint>::Only
+    };
+}
+
+/// The [`Other`](Disjoint::Other) filter of a "variant", in type position: `other!(Player)` is `<Player as Disjoint>::Other`.
+///
+/// See [`only!`](filters::only).
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __djqf_other {
+    ( $variant:ty ) => {
+        <$variant as $crate::Disjoint>::Other
+    };
+}
+
+/// The [`Any`](Disjoint::Any) filter of a "variant", in type position: `any!(Player)` is `<Player as Disjoint>::Any`.
+///
+/// See [`only!`](filters::only).
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __djqf_any {
+    ( $variant:ty ) => {
+        <$variant as $crate::Disjoint>::Any
+    };
+}
+
+/// The [`Not`](Disjoint::Not) filter of a "variant", in type position: `not!(Player)` is `<Player as Disjoint>::Not`.
+///
+/// See [`only!`](filters::only).
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __djqf_not {
+    ( $variant:ty ) => {
+        <$variant as $crate::Disjoint>::Not
+    };
+}
+
+/// The [`None`](Disjoint::None) filter of a "variant", in type position: `none!(Player)` is `<Player as Disjoint>::None`.
+///
+/// See [`only!`](filters::only).
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __djqf_none {
+    ( $variant:ty ) => {
+        <$variant as $crate::Disjoint>::None
+    };
+}
+
+/// Assert at compile time that the [`Only`](Disjoint::Only) filters of two "variants" never match the same entity,
+/// so queries with them can access the same components mutably in one system without error [B0001].
+///
+/// `assert_disjoint!(A, B)` fails to compile unless `A` and `B` are different members of the same group.
+/// For any other filters, which Bevy only checks when the system is initialized, use
+/// [`assert_is_system`](bevy_ecs::system::assert_is_system) in a test, which panics on a conflict.
+///
+/// [B0001]: https://bevyengine.org/learn/errors/b0001/
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::{Component, Query, Transform};
+/// use bevy_djqf::{assert_disjoint, disjoint, Disjoint};
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Player;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Enemy;
+///
+/// disjoint!(Player, Enemy);
+///
+/// assert_disjoint!(Player, Enemy);
+///
+/// fn system(
+///     _players: Query<&mut Transform, <Player as Disjoint>::Only>,
+///     _enemies: Query<&mut Transform, <Enemy as Disjoint>::Only>,
+/// ) {}
+///
+/// // panics if the queries conflict
+/// bevy::ecs::system::assert_is_system(system);
+/// ```
+///
+/// The same "variant" twice is rejected:
+/// ```compile_fail
+/// # use bevy::prelude::Component;
+/// use bevy_djqf::{assert_disjoint, disjoint};
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Player;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Enemy;
+///
+/// disjoint!(Player, Enemy);
+///
+/// assert_disjoint!(Player, Player);
+/// ```
+///
+/// As are "variants" from different groups, which may both be on one entity:
+/// ```compile_fail
+/// # use bevy::prelude::Component;
+/// use bevy_djqf::{assert_disjoint, disjoint};
+///
+/// #[derive(Component, Debug, Default)]
+/// struct A;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct B;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct X;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Y;
+///
+/// disjoint!(A, B);
+/// disjoint!(X, Y);
+///
+/// assert_disjoint!(A, Y);
+/// ```
+#[macro_export]
+macro_rules! assert_disjoint {
+    ( $a:ty, $b:ty $(,)? ) => {
+        const _: () = {
+            let _: fn() = $crate::__private::same_group::<$a, $b>;
+            ::core::assert!(
+                <$a as $crate::Disjoint>::INDEX != <$b as $crate::Disjoint>::INDEX,
+                ::core::concat!(
+                    "`",
+                    ::core::stringify!($a),
+                    "` and `",
+                    ::core::stringify!($b),
+                    "` are the same \"variant\""
+                ),
+            );
+        };
+    };
+}
+
+/// Check that the filters of every "variant" of a group match the entities they should, e.g. in the test suite of a game,
+/// by spawning one entity for every combination of "variants" and comparing what `Only`, `Other`, `Any`, `Not` and `None` match.
+///
+/// `assert_group_partitions!(G)` runs [`check_partitions::<G>()`](check_partitions), which panics with the name of the filter
+/// if one doesn't match. `assert_group_partitions!(name: G)` generates a `#[test]` function called `name` that runs it.
+/// Every "variant" must implement `Default`, to be inserted.
+///
+/// A group of `n` "variants" spawns `2^n` entities, so this takes a while for groups of more than about 16 "variants",
+/// and panics for more than 20.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::Component;
+/// use bevy_djqf::{assert_group_partitions, disjoint};
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Player;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Ally;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Enemy;
+///
+/// disjoint!(Player, Ally, Enemy);
+///
+/// assert_group_partitions!(Player);
+/// ```
+#[macro_export]
+macro_rules! assert_group_partitions {
+    ( $test:ident : $group:ty $(,)? ) => {
+        #[test]
+        fn $test() {
+            $crate::check_partitions::<$group>();
+        }
+    };
+
+    ( $group:ty $(,)? ) => {
+        $crate::check_partitions::<$group>()
+    };
+}
+
+/// Check that systems querying each of a list of "variants" with their `Only` filters never conflict,
+/// e.g. in the test suite of a large app, to make sure that the "variants" its systems are split over, maybe across plugins or features,
+/// are really from one group.
+///
+/// For every pair of "variants", this builds a system with a `Query<&mut _, <V as Disjoint>::Only>` for each,
+/// which Bevy rejects if an entity could match both, see [`check_systems_disjoint`].
+/// `assert_systems_disjoint!(A, B, C)` panics if any pair conflicts,
+/// and `assert_systems_disjoint!(name: A, B, C)` generates a `#[test]` function called `name` that does the same.
+///
+/// Unlike [`assert_disjoint!`], which rejects the same mistakes at compile time but only for two "variants",
+/// a conflict here is found when the test runs.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::Component;
+/// use bevy_djqf::{assert_systems_disjoint, disjoint};
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Player;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Ally;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Enemy;
+///
+/// disjoint!(Player, Ally, Enemy);
+///
+/// assert_systems_disjoint!(Player, Ally, Enemy);
+/// ```
+///
+/// In a test suite, e.g. in `tests/disjoint.rs`:
+/// ```ignore
+/// use bevy_djqf::assert_systems_disjoint;
+/// use my_game::{Ally, Enemy, Player};
+///
+/// assert_systems_disjoint!(teams_are_disjoint: Player, Ally, Enemy);
+/// ```
+///
+/// "Variants" from different groups, which may both be on one entity, conflict:
+/// ```should_panic
+/// # use bevy::prelude::Component;
+/// use bevy_djqf::{assert_systems_disjoint, disjoint};
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Player;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Enemy;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Flying;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Walking;
+///
+/// disjoint!(Player, Enemy);
+/// disjoint!(Flying, Walking);
+///
+/// assert_systems_disjoint!(Player, Enemy, Flying);
+/// ```
+#[macro_export]
+macro_rules! assert_systems_disjoint {
+    ( @pairs $first:ty $(, $rest:ty )* ) => {
+        $( $crate::check_systems_disjoint::<$first, $rest>(); )*
+        $crate::assert_systems_disjoint!(@pairs $( $rest ),*);
+    };
+
+    ( @pairs ) => {};
+
+    ( $test:ident : $( $variant:ty ),+ $(,)? ) => {
+        #[test]
+        fn $test() {
+            $crate::assert_systems_disjoint!(@pairs $( $variant ),+);
+        }
+    };
+
+    ( $( $variant:ty ),+ $(,)? ) => {{
+        $crate::assert_systems_disjoint!(@pairs $( $variant ),+);
+    }};
+}
+
+/// A trait for disjoint queries. The `Any`, `Other`, `Only`, `Not` and `None` associated types (and the `Group`) are generated by the [`disjoint!`] macro.
+///
+/// These can be used in queries like `Query<&mut Transform, <A as Disjoint>::Only>`.
+///
+/// Every filter is bounded by [`QueryFilter`], so generic code only needs `V: Disjoint` to use them,
+/// and `V: Disjoint + 'static` for a system, without repeating `V::Only: QueryFilter` and so on.
+/// The markers themselves are the type parameter, not references or wrappers of them, such as `&V`,
+/// which are what a query fetches rather than what it filters by.
+///
+/// # Example with generic code
+/// ```
+/// # use bevy::prelude::{App, Component, Entity, Query, Update, World};
+/// # use bevy::ecs::system::RunSystemOnce;
+/// use bevy_djqf::{disjoint, Disjoint};
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Player;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Enemy;
+///
+/// disjoint!(Player, Enemy);
+///
+/// fn count_only<V: Disjoint>(world: &mut World) -> usize {
+///     world.query_filtered::<(), V::Only>().iter(world).count()
+/// }
+///
+/// fn only_entities<V: Disjoint + 'static>(query: Query<Entity, V::Only>) -> Vec<Entity> {
+///     query.iter().collect()
+/// }
+///
+/// let mut world = World::new();
+/// let player = world.spawn(Player).id();
+/// world.spawn(Enemy);
+/// world.spawn((Player, Enemy));
+///
+/// assert_eq!(count_only::<Player>(&mut world), 1);
+/// assert_eq!(world.run_system_once(only_entities::<Player>), [player]);
+/// ```
+pub trait Disjoint {
+    /// Any entities for this "enum".
+    ///
+    /// This is the same for every member of the "enum", so it doesn't matter which member it is accessed through.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::{Component, World};
+    /// # use std::marker::PhantomData;
+    /// use bevy_djqf::{Disjoint, disjoint};
+    ///
+    /// #[derive(Component, Debug, Default)]
+    /// struct A;
+    ///
+    /// #[derive(Component, Debug, Default)]
+    /// struct B;
+    ///
+    /// disjoint!(A, B);
+    ///
+    /// // Only compiles if both filters are the same type
+    /// fn same_type<T>(_: PhantomData<T>, _: PhantomData<T>) {}
+    /// same_type(PhantomData::<<A as Disjoint>::Any>, PhantomData::<<B as Disjoint>::Any>);
+    ///
+    /// let mut world = World::new();
+    /// world.spawn(A);
+    /// world.spawn(B);
+    /// world.spawn_empty();
+    ///
+    /// let mut any = world.query_filtered::<(), <A as Disjoint>::Any>();
+    /// assert_eq!(any.iter(&world).count(), 2);
+    /// ```
+    type Any: QueryFilter;
+    /// Entities that do not have this specific "variant".
+    ///
+    /// This is `(Without<Self>, Or<(With<...>, ...)>)` over the other members, or `(Without<Self>, With<...>)`
+    /// in a group of 2, which is the same type as the [`Only`](Disjoint::Only) filter of the other member.
+    /// Filters are matched once per archetype, not per entity, so a flatter but equivalent filter
+    /// doesn't make iterating any faster, see the timings in `benches/filters.rs`.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::{Component, Entity, Or, With, Without, World};
+    /// # use std::marker::PhantomData;
+    /// use bevy_djqf::{Disjoint, disjoint};
+    ///
+    /// #[derive(Component, Debug, Default)]
+    /// struct Active;
+    ///
+    /// #[derive(Component, Debug, Default)]
+    /// struct Inactive;
+    ///
+    /// disjoint!(Active, Inactive);
+    ///
+    /// // Only compiles if both filters are the same type
+    /// fn same_type<T>(_: PhantomData<T>, _: PhantomData<T>) {}
+    /// same_type(PhantomData::<<Active as Disjoint>::Other>, PhantomData::<<Inactive as Disjoint>::Only>);
+    ///
+    /// let mut world = World::new();
+    /// world.spawn(Active);
+    /// world.spawn(Inactive);
+    /// world.spawn((Active, Inactive));
+    /// world.spawn_empty();
+    ///
+    /// // matches the same entities as the general form
+    /// let mut other = world.query_filtered::<Entity, <Active as Disjoint>::Other>();
+    /// let mut general = world.query_filtered::<Entity, (Without<Active>, Or<(With<Inactive>,)>)>();
+    /// assert_eq!(other.iter(&world).collect::<Vec<_>>(), general.iter(&world).collect::<Vec<_>>());
+    /// assert_eq!(other.iter(&world).count(), 1);
+    /// ```
+    type Other: QueryFilter;
+    /// Entities that only have this specific "variant".
+    type Only: QueryFilter;
+    /// Entities without this "variant", including those with no "variant" at all.
+    ///
+    /// This is just `Without<Self>`, unlike [`Other`](Disjoint::Other), which also requires another "variant".
+    /// So `Not` is `Other` together with [`None`](Disjoint::None), as long as no entity has more than one "variant".
+    /// For groups with a marker provided with `in`, it also requires that marker, like the other filters.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::{Component, Entity, World};
+    /// use bevy_djqf::{Disjoint, disjoint};
+    ///
+    /// #[derive(Component, Debug, Default)]
+    /// struct Player;
+    ///
+    /// #[derive(Component, Debug, Default)]
+    /// struct Enemy;
+    ///
+    /// disjoint!(Player, Enemy);
+    ///
+    /// let mut world = World::new();
+    /// world.spawn(Player);
+    /// let enemy = world.spawn(Enemy).id();
+    /// let untagged = world.spawn_empty().id();
+    ///
+    /// let mut not = world.query_filtered::<Entity, <Player as Disjoint>::Not>();
+    /// let mut not = not.iter(&world).collect::<Vec<_>>();
+    /// not.sort();
+    /// assert_eq!(not, [enemy, untagged]);
+    ///
+    /// let mut other = world.query_filtered::<Entity, <Player as Disjoint>::Other>();
+    /// assert_eq!(other.iter(&world).collect::<Vec<_>>(), [enemy]);
+    /// ```
+    type Not: QueryFilter;
+    /// Entities that have none of the "variants" of this "enum".
+    ///
+    /// This is the same for every member of the "enum".
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::{Component, World};
+    /// use bevy_djqf::{Disjoint, disjoint};
+    ///
+    /// #[derive(Component, Debug, Default)]
+    /// struct A;
+    ///
+    /// #[derive(Component, Debug, Default)]
+    /// struct B;
+    ///
+    /// #[derive(Component, Debug, Default)]
+    /// struct Untagged;
+    ///
+    /// disjoint!(A, B);
+    ///
+    /// let mut world = World::new();
+    /// world.spawn(A);
+    /// world.spawn(B);
+    /// world.spawn(Untagged);
+    ///
+    /// let mut none = world.query_filtered::<&Untagged, <A as Disjoint>::None>();
+    /// assert_eq!(none.iter(&world).count(), 1);
+    /// ```
+    type None: QueryFilter;
+    /// The "enum" that this "variant" belongs to. See [`DisjointGroup`].
+    type Group: DisjointGroup;
+    /// The number of "variants" in this "enum", or with explicit indices, one more than the largest [`INDEX`](Disjoint::INDEX).
+    ///
+    /// This is the same for every member of the "enum".
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::Component;
+    /// use bevy_djqf::{Disjoint, make_disjoint_markers};
+    ///
+    /// macro_rules! type_template {
+    ///     ($Name:ident) => {
+    ///         #[derive(Component, Debug, Default)]
+    ///         struct $Name;
+    ///     };
+    /// }
+    ///
+    /// make_disjoint_markers!(type_template for Player, FriendlyPlayer, EnemyPlayer, NonPlayerCharacter, FriendlyAi, EnemyAi, InanimateObject);
+    ///
+    /// assert_eq!(<Player as Disjoint>::COUNT, 7);
+    /// assert_eq!(<InanimateObject as Disjoint>::COUNT, 7);
+    ///
+    /// // Can be used to size arrays
+    /// let per_variant = [0u32; <EnemyAi as Disjoint>::COUNT];
+    /// assert_eq!(per_variant.len(), 7);
+    /// ```
+    const COUNT: usize;
+    /// The position of this "variant" in the list provided to the [`disjoint!`] macro, starting from `0`,
+    /// or the index given for it, e.g. `2` for `Wall` in `disjoint!(Player = 0, Wall = 2, Enemy = 1)`.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::Component;
+    /// use bevy_djqf::{Disjoint, disjoint};
+    ///
+    /// #[derive(Component, Debug, Default)]
+    /// struct A;
+    ///
+    /// #[derive(Component, Debug, Default)]
+    /// struct B;
+    ///
+    /// #[derive(Component, Debug, Default)]
+    /// struct C;
+    ///
+    /// disjoint!(A, B, C);
+    ///
+    /// assert_eq!(<A as Disjoint>::INDEX, 0);
+    /// assert_eq!(<B as Disjoint>::INDEX, 1);
+    /// assert_eq!(<C as Disjoint>::INDEX, 2);
+    /// ```
+    const INDEX: usize;
+    /// The name of this "variant", exactly as it was written in the list provided to the [`disjoint!`] macro.
+    ///
+    /// For types referred to by their path, this is the full path, e.g. `"enemies::Goblin"`.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::Component;
+    /// use bevy_djqf::{Disjoint, disjoint};
+    ///
+    /// #[derive(Component, Debug, Default)]
+    /// pub struct Player;
+    ///
+    /// mod enemies {
+    ///     # use bevy::prelude::Component;
+    ///     #[derive(Component, Debug, Default)]
+    ///     pub struct Goblin;
+    /// }
+    ///
+    /// disjoint!(Player, enemies::Goblin);
+    ///
+    /// assert_eq!(<Player as Disjoint>::NAME, "Player");
+    /// assert_eq!(<enemies::Goblin as Disjoint>::NAME, "enemies::Goblin");
+    /// ```
+    const NAME: &'static str;
+    /// The [`NAME`](Disjoint::NAME) of every "variant" of this "enum", in order, so that `NAMES[INDEX] == NAME`.
+    ///
+    /// This is the same for every member of the "enum". It has [`COUNT`](Disjoint::COUNT) names,
+    /// with `""` for an index without a "variant", see [`disjoint!`].
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::Component;
+    /// use bevy_djqf::{Disjoint, disjoint};
+    ///
+    /// #[derive(Component, Debug, Default)]
+    /// struct A;
+    ///
+    /// #[derive(Component, Debug, Default)]
+    /// struct B;
+    ///
+    /// #[derive(Component, Debug, Default)]
+    /// struct C;
+    ///
+    /// disjoint!(A, B, C);
+    ///
+    /// assert_eq!(<A as Disjoint>::NAMES, ["A", "B", "C"]);
+    /// assert_eq!(<C as Disjoint>::NAMES[<B as Disjoint>::INDEX], <B as Disjoint>::NAME);
+    /// ```
+    const NAMES: &'static [&'static str];
+}
+
+/// [`<V as Disjoint>::Only`](Disjoint::Only), combined with the additional filter `F`.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::{Component, With, World};
+/// use bevy_djqf::{disjoint, OnlyWith};
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Player;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Enemy;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Alive;
+///
+/// disjoint!(Player, Enemy);
+///
+/// let mut world = World::new();
+/// world.spawn((Player, Alive));
+/// world.spawn(Player);
+/// world.spawn((Enemy, Alive));
+///
+/// let mut alive_players = world.query_filtered::<(), OnlyWith<Player, With<Alive>>>();
+/// assert_eq!(alive_players.iter(&world).count(), 1);
+/// ```
+pub type OnlyWith<V, F> = (<V as Disjoint>::Only, F);
+
+/// [`<V as Disjoint>::Other`](Disjoint::Other), combined with the additional filter `F`.
+pub type OtherWith<V, F> = (<V as Disjoint>::Other, F);
+
+/// [`<V as Disjoint>::Any`](Disjoint::Any), combined with the additional filter `F`.
+pub type AnyWith<V, F> = (<V as Disjoint>::Any, F);
+
+/// A group of disjoint types, i.e. the "enum" that each [`Disjoint`] type is a "variant" of.
+///
+/// The [`disjoint!`] macro implements this for the first type in the list, which then represents the group.
+/// For any member, the group is `<A as Disjoint>::Group`.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::Component;
+/// use bevy_djqf::{Disjoint, DisjointGroup, disjoint};
+///
+/// #[derive(Component, Debug, Default)]
+/// struct A;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct B;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct C;
+///
+/// disjoint!(A, B, C);
+///
+/// // Every member has the same group, with all three members
+/// let _: <A as DisjointGroup>::Members = (A, B, C);
+/// let _: <<B as Disjoint>::Group as DisjointGroup>::Members = (A, B, C);
+/// let _: <<C as Disjoint>::Group as DisjointGroup>::Members = (A, B, C);
+/// ```
+///
+/// Code that works with any group can be written once against `G: DisjointGroup`:
+/// ```
+/// # use bevy::prelude::Component;
+/// use bevy_djqf::{disjoint, Disjoint, DisjointGroup, MemberVisitor};
+///
+/// #[derive(Component, Debug, Default)]
+/// struct A;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct B;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct C;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct X;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Y;
+///
+/// disjoint!(A, B, C);
+/// disjoint!(X, Y);
+///
+/// struct Count(usize);
+///
+/// impl MemberVisitor for Count {
+///     fn visit<M: Disjoint + Component>(&mut self) {
+///         self.0 += 1;
+///     }
+/// }
+///
+/// fn count_members<G: DisjointGroup>() -> usize {
+///     let mut count = Count(0);
+///     G::for_each_member(&mut count);
+///     count.0
+/// }
+///
+/// assert_eq!(count_members::<A>(), 3);
+/// assert_eq!(count_members::<<Y as Disjoint>::Group>(), 2);
+/// assert_eq!(count_members::<A>(), <A as DisjointGroup>::COUNT);
+/// ```
+pub trait DisjointGroup {
+    /// A tuple of every member of this "enum", in the order they were provided to the [`disjoint!`] macro.
+    type Members;
+
+    /// The number of members of this "enum", which is the same as [`Disjoint::COUNT`] for every member.
+    ///
+    /// With explicit indices, this is one more than the largest [`INDEX`](Disjoint::INDEX), see [`disjoint!`].
+    const COUNT: usize;
+
+    /// An array with one `T` for each member of this "enum", i.e. `[T; COUNT]`.
+    /// With explicit indices, the slot of an index without a member is unused.
+    ///
+    /// `[T; G::COUNT]` can't be used for a generic `G` on stable Rust, so the [`disjoint!`] macro
+    /// sets this to the concrete array type, e.g. `[T; 3]` for three members, for [`PerVariant`] and generic code.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::Component;
+    /// use bevy_djqf::{disjoint, Disjoint, DisjointGroup, VariantArray};
+    ///
+    /// #[derive(Component, Debug, Default)]
+    /// struct A;
+    ///
+    /// #[derive(Component, Debug, Default)]
+    /// struct B;
+    ///
+    /// #[derive(Component, Debug, Default)]
+    /// struct C;
+    ///
+    /// disjoint!(A, B, C);
+    ///
+    /// let speeds: <A as DisjointGroup>::Array<f32> = VariantArray::from_fn(|index| index as f32);
+    /// let speeds: [f32; 3] = speeds;
+    /// assert_eq!(speeds[<C as Disjoint>::INDEX], 2.0);
+    ///
+    /// fn first<G: DisjointGroup>(values: &G::Array<&'static str>) -> &'static str {
+    ///     values.as_ref()[0]
+    /// }
+    /// assert_eq!(first::<A>(&["a", "b", "c"]), "a");
+    /// ```
+    type Array<T>: VariantArray<T>;
+
+    /// The [`NAME`](Disjoint::NAME) of every member of this "enum", in order, with `""` for an index without a member.
+    const NAMES: &'static [&'static str];
+
+    /// Call [`MemberVisitor::visit`] for every member of this "enum", in the order of their [`INDEX`](Disjoint::INDEX).
+    fn for_each_member<V: MemberVisitor>(visitor: &mut V);
+
+    /// Everything about this "enum" in one value, e.g. for tooling. See [`DisjointInfo`].
+    const INFO: DisjointInfo = DisjointInfo {
+        count: Self::COUNT,
+        names: Self::NAMES,
+        type_ids: visitors::member_type_ids::<Self>,
+    };
+
+    // overridden by `disjoint!` to call `ReflectMembers::register` if the group implements it,
+    // so that `add_disjoint_group` doesn't need a `ReflectMembers` bound
+    #[cfg(feature = "reflect")]
+    #[doc(hidden)]
+    fn __register_reflected(_app: &mut __private::App) {}
+}
+
+/// The metadata of a group, from [`DisjointGroup::INFO`], so that tooling can read a single constant.
+///
+/// `#[derive(Disjoint)]` also generates an `INFO` constant on the enum with this value.
 ///
-/// # Usage
+/// # Example
+/// ```
+/// # use bevy::prelude::Component;
+/// # use std::any::TypeId;
+/// use bevy_djqf::{disjoint, DisjointGroup, DisjointInfo};
 ///
-/// `disjoint!(A, B);`
+/// #[derive(Component, Debug, Default)]
+/// struct Player;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Enemy;
+///
+/// disjoint!(Player, Enemy);
+///
+/// const GAME_OBJECT_INFO: DisjointInfo = <Player as DisjointGroup>::INFO;
+///
+/// assert_eq!(GAME_OBJECT_INFO.count, 2);
+/// assert_eq!(GAME_OBJECT_INFO.names, ["Player", "Enemy"]);
+/// assert_eq!(GAME_OBJECT_INFO.type_ids(), [TypeId::of::<Player>(), TypeId::of::<Enemy>()]);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct DisjointInfo {
+    /// The number of "variants", [`DisjointGroup::COUNT`].
+    pub count: usize,
+    /// The name of every "variant", in order, [`DisjointGroup::NAMES`].
+    pub names: &'static [&'static str],
+    type_ids: fn() -> Vec<std::any::TypeId>,
+}
+
+impl DisjointInfo {
+    /// The [`TypeId`](std::any::TypeId) of every "variant", in the order of their [`INDEX`](Disjoint::INDEX).
+    /// With explicit indices, an index without a "variant" is skipped.
+    ///
+    /// These can't be computed in a constant, so they are collected each time this is called.
+    pub fn type_ids(&self) -> Vec<std::any::TypeId> {
+        (self.type_ids)()
+    }
+}
+
+/// A [`DisjointGroup`] with a default "variant", marked with `#[default]` in the [`disjoint!`] macro,
+/// e.g. for newly spawned entities with [`DisjointCommandsExt::spawn_default`].
+///
+/// Like [`DisjointGroup`], this is implemented for the first type provided to the macro.
+pub trait DefaultVariant: DisjointGroup {
+    /// The default "variant".
+    type Default: Disjoint<Group = Self> + Component + Default;
+
+    /// The [`INDEX`](Disjoint::INDEX) of the default "variant".
+    const DEFAULT_INDEX: usize = <Self::Default as Disjoint>::INDEX;
+}
+
+/// A "variant" that is a sub-"variant" of a `Parent` "variant", generated by the [`disjoint_tree!`] macro.
+///
+/// When this "variant" is added to an entity, its `Parent` is set too.
+pub trait Subvariant: Disjoint {
+    /// The "variant" this is a sub-"variant" of.
+    type Parent: Disjoint;
+}
+
+/// Implemented for every member of the group `G`, and nothing else, for bounds like `where T: GroupMember<G>`
+/// in generic code.
+///
+/// This is sealed, and implemented by the [`disjoint!`] macro through [`Disjoint::Group`].
 ///
 /// # Example
 /// ```
-/// # use bevy::prelude::{App, Component, Update, Query, Transform};
-/// use bevy_djqf::{Disjoint, disjoint};
+/// # use bevy::prelude::Component;
+/// use bevy_djqf::{disjoint, Disjoint, GroupMember};
 ///
 /// #[derive(Component, Debug, Default)]
 /// struct A;
@@ -21,93 +2636,208 @@
 ///
 /// disjoint!(A, B);
 ///
-/// fn only_a(_query: Query<&mut Transform, <A as Disjoint>::Only>) {}
-///
-/// fn except_a(_query: Query<&mut Transform, <A as Disjoint>::Other>) {}
+/// fn index_in_group<G, T: GroupMember<G>>() -> usize {
+///     T::INDEX
+/// }
 ///
-/// # App::new().add_systems(Update, (only_a, except_a));
+/// assert_eq!(index_in_group::<A, B>(), 1);
 /// ```
-#[macro_export]
-macro_rules! disjoint {
-    // entry point: 2+ types
-    ( $current:ty, $( $rest:ty ),* ) => {
-        $crate::disjoint!(@for [] $current [ $( $rest , )* ]);
-    };
+///
+/// Types from other groups are rejected, e.g. with `disjoint!(X, Y);`, `index_in_group::<A, X>()` fails to compile
+/// because `X` doesn't implement `GroupMember<A>`.
+pub trait GroupMember<G>: Disjoint<Group = G> + sealed::Sealed<G> {}
 
-    // entry point: 1 type
-    ( $current:ty ) => {
-        const _: () = panic!("You must provide at least two types");
-    };
+impl<M: Disjoint<Group = G>, G> GroupMember<G> for M {}
 
-    // entry point: 0 types
-    () => {
-        const _: () = panic!("You must provide at least two types");
-    };
+mod sealed {
+    pub trait Sealed<G> {}
 
-    // 2+ remaining
-    (@for [ $( $consumed:ty , )* ] $current:ty [ $next:ty , $( $later:ty , )* ]) => {
-        $crate::disjoint!(@imp [ $( $consumed , )* ] $current [ $next , $( $later , )* ]);
-        $crate::disjoint!(@for [ $( $consumed , )* $current , ] $next [ $( $later , )* ]);
-    };
+    impl<M: crate::Disjoint<Group = G>, G> Sealed<G> for M {}
+}
 
-    // 1 remaining
-    (@for [ $( $consumed:ty , )* ] $current:ty [ $next:ty ]) => {
-        $crate::disjoint!(@imp [ $( $consumed , )* ] $current [ $next ]);
-        $crate::disjoint!(@for [ $( $consumed , )* $current , ] $next []);
-    };
+/// A value-level enum with one variant for each member of a [`DisjointGroup`], in the same order.
+///
+/// Implemented for the enum by the [`Disjoint`](derive@Disjoint) derive macro. The "variant" of an entity can be set
+/// from a value with [`DisjointEntityCommandsExt::set_variant_value`], and read back as a value with
+/// [`DisjointQuery::variant`] and [`from_index`](DisjointEnum::from_index).
+pub trait DisjointEnum: Sized {
+    /// The group of marker components, one for each variant of this enum.
+    type Group: DisjointGroup;
 
-    // 0 remaining
-    (@for [ $( $consumed:ty , )* ] $current:ty []) => {
-        $crate::disjoint!(@imp [ $( $consumed , )* ] $current []);
-    };
+    /// The [`INDEX`](Disjoint::INDEX) of the marker component for this value.
+    fn index(&self) -> usize;
 
-    (@imp [ $( $before:ty , )* ] $current:ty [ $( $after:ty , )* ]) => {
-        impl Disjoint for $current {
+    /// The value whose marker component has this [`INDEX`](Disjoint::INDEX), if any.
+    fn from_index(index: usize) -> Option<Self>;
 
-            type Any = bevy_ecs::query::Or<(
-                $(bevy_ecs::query::With<$before> , )*
-                bevy_ecs::query::With<$current> ,
-                $(bevy_ecs::query::With<$after> , )*
-            )>;
+    /// Every value, in the order of their [`INDEX`](Disjoint::INDEX), e.g. to list them in a UI.
+    ///
+    /// The derive macro also generates an `ALL` constant with the same values, as an array.
+    fn iter() -> impl Iterator<Item = Self> {
+        (0..<Self::Group as DisjointGroup>::COUNT).filter_map(Self::from_index)
+    }
+}
 
-            type Other = (
-                bevy_ecs::query::Without<$current> ,
-                bevy_ecs::query::Or<(
-                    $(bevy_ecs::query::With<$before> , )*
-                    $(bevy_ecs::query::With<$after> , )*
-                )>
-            );
+/// Something that can be run for every member of a [`DisjointGroup`], with [`DisjointGroup::for_each_member`].
+pub trait MemberVisitor {
+    /// Called once for each member `M` of the group.
+    fn visit<M: Disjoint + Component>(&mut self);
+}
 
-            type Only = (
-                $(bevy_ecs::query::Without<$before> , )*
-                bevy_ecs::query::With<$current> ,
-                $(bevy_ecs::query::Without<$after> , )*
-            );
+/// Run a block of code once for each "variant" type `V` of a group, e.g. to register systems or resources per "variant".
+///
+/// `for_each_variant!(G, |V| { ... })` runs the block for every member of the [`DisjointGroup`] `G`, in order,
+/// with `V` as the type of the member, which implements [`Disjoint`] and `Component`.
+/// This is a [`MemberVisitor`] written inline, so the block is a generic function body, and can't capture local variables,
+/// except for one mutable reference, given as `for_each_variant!(G, |V, app: &mut App| { ... })`.
+///
+/// # Example
+/// ```
+/// # use std::marker::PhantomData;
+/// # use bevy::prelude::{App, Component, Resource};
+/// use bevy_djqf::{disjoint, for_each_variant, Disjoint};
+///
+/// #[derive(Component, Debug, Default)]
+/// struct A;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct B;
+///
+/// disjoint!(A, B);
+///
+/// #[derive(Resource)]
+/// struct Count<V>(usize, PhantomData<V>);
+///
+/// let mut app = App::new();
+/// let app = &mut app;
+/// for_each_variant!(A, |V, app: &mut App| {
+///     app.insert_resource(Count::<V>(V::INDEX + 10, PhantomData));
+/// });
+///
+/// assert_eq!(app.world().resource::<Count<A>>().0, 10);
+/// assert_eq!(app.world().resource::<Count<B>>().0, 11);
+///
+/// let mut names = Vec::new();
+/// let names_ref = &mut names;
+/// for_each_variant!(A, |V, names_ref: &mut Vec<&'static str>| names_ref.push(V::NAME));
+/// assert_eq!(names, ["A", "B"]);
+/// ```
+#[macro_export]
+macro_rules! for_each_variant {
+    ( $group:ty, |$V:ident| $body:expr $(,)? ) => {{
+        // the struct isn't hygienic, so it mustn't shadow a type used in `$group` or `$body`
+        struct __ForEachVariant;
 
+        impl $crate::MemberVisitor for __ForEachVariant {
+            fn visit<$V: $crate::Disjoint + $crate::__private::Component>(&mut self) {
+                $body;
+            }
         }
-    };
 
-    ( $($invalid_input:tt)* ) => {
-        const _: () = panic!(
-            concat!(
-                "Invalid input `",
-                stringify!($($invalid_input)*),
-                "` to macro `disjoint!`. Use the form `disjoint!(A, B)`"
-            )
-        );
-    };
+        <$group as $crate::DisjointGroup>::for_each_member(&mut __ForEachVariant);
+    }};
+
+    ( $group:ty, |$V:ident, $state:ident : &mut $State:ty| $body:expr $(,)? ) => {{
+        struct __ForEachVariant<'a>(&'a mut $State);
+
+        impl $crate::MemberVisitor for __ForEachVariant<'_> {
+            fn visit<$V: $crate::Disjoint + $crate::__private::Component>(&mut self) {
+                let $state: &mut $State = &mut *self.0;
+                $body;
+            }
+        }
+
+        <$group as $crate::DisjointGroup>::for_each_member(&mut __ForEachVariant($state));
+    }};
 }
 
-/// A trait for disjoint queries. The `Any`, `Other`, and `Only` associated types are generated by the [`disjoint!`] macro.
+/// Add one system for each of the provided "variants" to a schedule, generated by a template macro.
 ///
-/// These can be used in queries like `Query<&mut Transform, <A as Disjoint>::Only>`.
-pub trait Disjoint {
-    /// Any entities for this "enum".
-    type Any;
-    /// Entities that do not have this specific "variant".
-    type Other;
-    /// Entities that only have this specific "variant".
-    type Only;
+/// `per_variant_systems!(app, Update, system_template for A, B, C)` adds `system_template!(A)`, `system_template!(B)`
+/// and `system_template!(C)` to the `Update` schedule of `app` (an `App` or `&mut App`), where `system_template` is
+/// the name of a macro that receives each type as `($V:ty)` and produces a system, e.g. a closure.
+/// The template is expanded once for each type, so the types are listed, as in [`make_disjoint_markers`].
+///
+/// All of the "variants" must be members of the same group. When the template only queries with
+/// [`<$V as Disjoint>::Only`](Disjoint::Only), the systems are disjoint from each other, so they can access
+/// the same components mutably and still run in parallel.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::{App, Component, Query, Transform, Update};
+/// use bevy_djqf::{disjoint, per_variant_systems, Disjoint};
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Player;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Enemy;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Wall;
+///
+/// disjoint!(Player, Enemy, Wall);
+///
+/// // one system for each "variant", moving its entities by its `INDEX`
+/// macro_rules! move_template {
+///     ($V:ty) => {
+///         |mut query: Query<&mut Transform, <$V as Disjoint>::Only>| {
+///             for mut transform in &mut query {
+///                 transform.translation.x += <$V as Disjoint>::INDEX as f32;
+///             }
+///         }
+///     };
+/// }
+///
+/// let mut app = App::new();
+/// per_variant_systems!(app, Update, move_template for Player, Enemy, Wall);
+///
+/// let enemy = app.world_mut().spawn((Enemy, Transform::default())).id();
+/// let wall = app.world_mut().spawn((Wall, Transform::default())).id();
+/// app.update();
+///
+/// assert_eq!(app.world().get::<Transform>(enemy).unwrap().translation.x, 1.0);
+/// assert_eq!(app.world().get::<Transform>(wall).unwrap().translation.x, 2.0);
+/// ```
+///
+/// "Variants" from different groups are rejected:
+/// ```compile_fail
+/// # use bevy::prelude::{App, Component, Update};
+/// use bevy_djqf::{disjoint, per_variant_systems};
+///
+/// #[derive(Component, Debug, Default)]
+/// struct A;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct B;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct X;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Y;
+///
+/// disjoint!(A, B);
+/// disjoint!(X, Y);
+///
+/// macro_rules! system_template {
+///     ($V:ty) => {
+///         || {}
+///     };
+/// }
+///
+/// let mut app = App::new();
+/// per_variant_systems!(app, Update, system_template for A, X);
+/// ```
+#[macro_export]
+macro_rules! per_variant_systems {
+    ( $app:expr, $schedule:expr, $system_template_macro:ident for $first:ty $(, $rest:ty )* $(,)? ) => {{
+        $( let _: fn() = $crate::__private::same_group::<$first, $rest>; )*
+
+        let schedule = $schedule;
+        $app.add_systems(::core::clone::Clone::clone(&schedule), $system_template_macro!($first));
+        $( $app.add_systems(::core::clone::Clone::clone(&schedule), $system_template_macro!($rest)); )*
+    }};
 }
 
 /// Generate marker types for disjoint query filters for the provided list of names.
@@ -118,6 +2848,43 @@ pub trait Disjoint {
 ///
 /// `make_disjoint_markers!(type_template for A, B)` where `type_template` is the name of the macro.
 ///
+/// `make_disjoint_markers!(pub type_template for A, B)` forwards the visibility to the `type_template`,
+/// which then receives it as `($vis:vis $Name:ident)`. Any visibility is accepted, e.g. `pub(crate)` or `pub(in crate::path)`.
+/// The generated [`Disjoint`] impls are trait impls, so they are available wherever the marker types are.
+///
+/// `make_disjoint_markers!(type_template, derive(Component, Reflect) for A, B)` forwards the derives to the `type_template`,
+/// which then receives them as an attribute, e.g. `($(#[$attr:meta])* $Name:ident)`, or `($(#[$attr:meta])* $vis:vis $Name:ident)` with a visibility.
+/// With the `reflect` feature, `Reflect` is added to these derives if it isn't one of them already, as in
+/// [`#[derive(Disjoint)]`](derive@Disjoint), so the markers can be registered with `DisjointReflectAppExt`.
+/// Markers without derives, and generic markers, are left to the template.
+///
+/// `make_disjoint_markers!(type_template for A, B; aliases)` also generates a type alias for the
+/// [`Only`](Disjoint::Only), [`Other`](Disjoint::Other) and [`Any`](Disjoint::Any) filters of each name,
+/// e.g. `AOnly`, `AOther` and `AAny`, with the same visibility as the markers.
+/// Their names can be changed with `aliases(prefix = "Q", suffix = "Filter")`, e.g. `QAOnlyFilter`.
+/// This is opt-in to avoid name collisions, and requires the `derive` feature.
+///
+/// `make_disjoint_markers!(type_template, #[component(storage = "SparseSet")] for A, B)` forwards the attributes
+/// to the `type_template` for every name, in the same `$(#[$attr:meta])*` position, after any derives, e.g.
+/// `make_disjoint_markers!(type_template, derive(Component), #[component(storage = "SparseSet")] for A, B)`.
+/// This is also how a common `#[require(...)]` would be added to every marker on Bevy versions with required components
+/// (this crate currently targets Bevy 0.14, which doesn't have them). The attributes aren't passed on to [`disjoint`].
+///
+/// `make_disjoint_markers!(type_template for <T: Send + Sync + 'static> Height, Width)` generates generic markers,
+/// with the same generic parameters, which are disjoint for each `T` (see [`disjoint`]). The `type_template` then receives
+/// the parameters and the bounds (as a where clause) after the name, e.g. `($Name:ident<$($T:ident),+> where $($bounds:tt)*)`.
+/// Bounds that aren't single tokens go in a trailing where clause, e.g. `for <T> Height, Width where T: std::fmt::Debug`.
+/// Aliases aren't supported for generic markers.
+///
+/// The `type_template` only has to define a type with the given name, so it can also generate tuple structs,
+/// structs with fields, or enums, as long as they are components. Markers without a [`Default`] impl can't be used with
+/// [`set_variant`](DisjointEntityCommandsExt::set_variant), but can be inserted with a value with
+/// [`insert_variant`](DisjointEntityCommandsExt::insert_variant), see the example with fields below.
+///
+/// Each name may be preceded by doc comments and attributes, e.g. `make_disjoint_markers!(type_template for /// The player
+/// Player, Enemy)`, which are forwarded to the `type_template` for that name only (after any derives), in the same
+/// `$(#[$attr:meta])*` position. Only the bare names are passed on to [`disjoint`].
+///
 /// # Example
 /// ```
 /// # use bevy::prelude::{App, Component, Update, Query, Transform};
@@ -143,21 +2910,295 @@ pub trait Disjoint {
 ///
 /// # App::new().add_systems(Update, (player_only, any));
 /// ```
+///
+/// # Example with derives
+/// ```
+/// # use bevy::prelude::Component;
+/// use bevy_djqf::make_disjoint_markers;
+///
+/// // The template receives the derives as an attribute
+/// macro_rules! type_template {
+///     ($(#[$attr:meta])* $Name:ident) => {
+///         $(#[$attr])*
+///         struct $Name;
+///     };
+/// }
+///
+/// make_disjoint_markers!(type_template, derive(Component, Debug, Default, PartialEq) for Player, Enemy);
+///
+/// assert_eq!(Player::default(), Player);
+/// ```
+///
+/// # Example with common attributes
+/// ```
+/// # use bevy::prelude::Component;
+/// use bevy::ecs::component::StorageType;
+/// use bevy_djqf::{make_disjoint_markers, Disjoint};
+///
+/// macro_rules! type_template {
+///     ($(#[$attr:meta])* $Name:ident) => {
+///         $(#[$attr])*
+///         struct $Name;
+///     };
+/// }
+///
+/// make_disjoint_markers!(
+///     type_template,
+///     derive(Component, Debug, Default),
+///     #[component(storage = "SparseSet")]
+///     for Player, Enemy
+/// );
+///
+/// assert_eq!(<Player as Component>::STORAGE_TYPE, StorageType::SparseSet);
+/// assert_eq!(<Enemy as Component>::STORAGE_TYPE, StorageType::SparseSet);
+/// assert_eq!(<Enemy as Disjoint>::NAMES, ["Player", "Enemy"]);
+/// ```
+///
+/// # Example with per-variant attributes
+/// ```
+/// # use bevy::prelude::Component;
+/// use bevy_djqf::{make_disjoint_markers, Disjoint};
+///
+/// macro_rules! type_template {
+///     ($(#[$attr:meta])* $Name:ident) => {
+///         #[derive(Component, Debug, Default)]
+///         $(#[$attr])*
+///         struct $Name;
+///     };
+/// }
+///
+/// make_disjoint_markers!(type_template for
+///     /// The entity controlled by the player.
+///     #[derive(Clone, Copy)]
+///     Player,
+///     /// Any entity controlled by the game.
+///     Enemy,
+/// );
+///
+/// let player = Player;
+/// let _copy = player;
+/// assert_eq!(<Enemy as Disjoint>::NAMES, ["Player", "Enemy"]);
+/// ```
+///
+/// # Example with aliases
+/// ```
+/// # use bevy::prelude::{App, Component, Update, Query, Transform};
+/// use bevy_djqf::make_disjoint_markers;
+///
+/// macro_rules! type_template {
+///     ($Name:ident) => {
+///         #[derive(Component, Debug, Default)]
+///         struct $Name;
+///     };
+/// }
+///
+/// make_disjoint_markers!(type_template for Player, Enemy; aliases);
+///
+/// fn player_only(
+///     _player_only: Query<&mut Transform, PlayerOnly>,
+///     _others: Query<&mut Transform, PlayerOther>,
+/// ) {}
+///
+/// fn any(_query: Query<&mut Transform, EnemyAny>) {}
+///
+/// # App::new().add_systems(Update, (player_only, any));
+/// ```
+///
+/// # Example with fields
+/// ```
+/// # use bevy::prelude::{Component, Entity, World};
+/// use bevy_djqf::{make_disjoint_markers, Disjoint, DisjointEntityWorldMutExt};
+///
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// struct TeamId(u32);
+///
+/// // The markers are tuple structs, which need a value to be constructed
+/// macro_rules! type_template {
+///     ($Name:ident) => {
+///         #[derive(Component, Debug)]
+///         struct $Name(pub TeamId);
+///     };
+/// }
+///
+/// make_disjoint_markers!(type_template for Attacker, Defender);
+///
+/// let mut world = World::new();
+/// let entity = world.spawn(Attacker(TeamId(1))).id();
+/// world.spawn(Defender(TeamId(2)));
+///
+/// world.entity_mut(entity).insert_variant(Defender(TeamId(1)));
+/// assert!(!world.entity(entity).contains::<Attacker>());
+///
+/// let mut defenders = world.query_filtered::<&Defender, <Defender as Disjoint>::Only>();
+/// let mut teams = defenders.iter(&world).map(|defender| defender.0).collect::<Vec<_>>();
+/// teams.sort_by_key(|team| team.0);
+/// assert_eq!(teams, [TeamId(1), TeamId(2)]);
+/// ```
+///
+/// # Example with generic markers
+/// ```
+/// # use bevy::prelude::{Component, Entity, Query, World};
+/// # use bevy::ecs::system::RunSystemOnce;
+/// # use std::marker::PhantomData;
+/// use bevy_djqf::{make_disjoint_markers, Disjoint};
+///
+/// // The template receives the generic parameters and their bounds
+/// macro_rules! type_template {
+///     ($Name:ident<$($T:ident),+> where $($bounds:tt)*) => {
+///         #[derive(Component)]
+///         struct $Name<$($T),+>(PhantomData<fn() -> ($($T,)+)>) where $($bounds)*;
+///     };
+/// }
+///
+/// make_disjoint_markers!(type_template for <T: Send + Sync + 'static> Leader, Follower);
+///
+/// struct Red;
+///
+/// struct Blue;
+///
+/// let mut world = World::new();
+/// let red_leader = world.spawn(Leader::<Red>(PhantomData)).id();
+/// world.spawn(Follower::<Red>(PhantomData));
+/// world.spawn(Leader::<Blue>(PhantomData));
+/// world.spawn((Leader::<Red>(PhantomData), Follower::<Red>(PhantomData)));
+///
+/// let red_leaders = world.run_system_once(|query: Query<Entity, <Leader<Red> as Disjoint>::Only>| {
+///     query.iter().collect::<Vec<_>>()
+/// });
+/// assert_eq!(red_leaders, [red_leader]);
+/// ```
+///
+/// # Example with visibility
+/// ```
+/// # use bevy::prelude::{App, Update, Query, Transform};
+/// use bevy_djqf::Disjoint;
+///
+/// mod markers {
+///     use bevy::prelude::Component;
+///
+///     // The template receives the visibility before the name
+///     macro_rules! type_template {
+///         ($vis:vis $Name:ident) => {
+///             #[derive(Component, Debug, Default)]
+///             $vis struct $Name;
+///         };
+///     }
+///
+///     bevy_djqf::make_disjoint_markers!(pub type_template for Player, Enemy);
+///
+///     pub mod restricted {
+///         use bevy::prelude::Component;
+///
+///         macro_rules! type_template {
+///             ($vis:vis $Name:ident) => {
+///                 #[derive(Component, Debug, Default)]
+///                 $vis struct $Name;
+///             };
+///         }
+///
+///         bevy_djqf::make_disjoint_markers!(pub(super) type_template for Visible, Hidden);
+///     }
+///
+///     // `Visible` is only visible within `markers`
+///     fn visible_only(
+///         _query: bevy::prelude::Query<&mut bevy::prelude::Transform, <restricted::Visible as bevy_djqf::Disjoint>::Only>,
+///     ) {}
+///
+///     pub fn plugin(app: &mut bevy::prelude::App) {
+///         app.add_systems(bevy::prelude::Update, visible_only);
+///     }
+/// }
+///
+/// fn player_only(_query: Query<&mut Transform, <markers::Player as Disjoint>::Only>) {}
+///
+/// # App::new().add_plugins(markers::plugin).add_systems(Update, player_only);
+/// ```
 #[macro_export]
 macro_rules! make_disjoint_markers {
-    ($type_template_macro:ident for $($Name:ident),*) => {
+    // no aliases
+    (@aliases [ $($vis:tt)* ] [ $($Name:ident),* ]) => {};
+
+    // aliases, with or without options
+    (@aliases [ $($vis:tt)* ] [ $($Name:ident),* ] $($aliases:tt)+) => {
+        $crate::__private::disjoint_aliases!($($vis)*; $($Name),*; $($aliases)+);
+    };
+
+    // one marker: the common attributes, then the attributes of this name, then the visibility
+    (@marker $type_template_macro:ident [ $($common:tt)* ] [ $($variant_attr:tt)* ] [ $($vis:tt)* ] $Name:ident) => {
+        $type_template_macro!($($common)* $($variant_attr)* $($vis)* $Name);
+    };
+
+    // one generic marker: as for one marker, then the generic parameters and the where clause
+    (@marker $type_template_macro:ident [ $($common:tt)* ] [ $($variant_attr:tt)* ] [ $($vis:tt)* ] [ $( $param:ident ),+ ] [ $($predicate:tt)* ] $Name:ident) => {
+        $type_template_macro!($($common)* $($variant_attr)* $($vis)* $Name< $( $param ),+ > where $($predicate)*);
+    };
+
+    // the generic types, collected one name at a time, since the parameters can't be repeated along with the names
+    (@disjoint [ $( $param:ident ),+ ] [ $($predicate:tt)* ] [ $($types:tt)* ] $Name:ident $(, $rest:ident )*) => {
+        $crate::make_disjoint_markers!(@disjoint [ $( $param ),+ ] [ $($predicate)* ] [ $($types)* $Name< $( $param ),+ >, ] $( $rest ),*);
+    };
+
+    (@disjoint [ $( $param:ident ),+ ] [ $($predicate:tt)* ] [ $($types:tt)* ]) => {
+        $crate::disjoint!(< $( $param ),+ > $($types)* where $($predicate)*);
+    };
+
+    // generic markers, with the generic parameters and the where clause captured as single groups like the attributes
+    (@markers $vis:tt $common:tt $type_template_macro:ident for < $( $param:ident $( : $bound:tt $( + $bounds:tt )* )? ),+ $(,)? > $( $(#[$variant_attr:meta])* $Name:ident ),* $(,)? $( where $($where:tt)* )?) => {
+        $crate::make_disjoint_markers!(
+            @generic $vis $common $type_template_macro [ $( $param ),+ ] [ $( $( $param : $bound $( + $bounds )* , )? )+ $( $($where)* )? ]
+            for $( $(#[$variant_attr])* $Name ),*
+        );
+    };
+
+    (@generic $vis:tt $common:tt $type_template_macro:ident $params:tt $predicates:tt for $( $(#[$variant_attr:meta])* $Name:ident ),*) => {
+        $(
+            $crate::make_disjoint_markers!(@marker $type_template_macro $common [ $(#[$variant_attr])* ] $vis $params $predicates $Name);
+        )*
+
+        $crate::make_disjoint_markers!(@disjoint $params $predicates [] $($Name),*);
+    };
+
+    // the visibility and the common attributes are captured as single groups, so they can be repeated for each name
+    (@markers $vis:tt $common:tt $type_template_macro:ident for $( $(#[$variant_attr:meta])* $Name:ident ),* $(,)? $( ; $($aliases:tt)+ )?) => {
         $(
-            $type_template_macro!($Name);
+            $crate::make_disjoint_markers!(@marker $type_template_macro $common [ $(#[$variant_attr])* ] $vis $Name);
         )*
 
         $crate::disjoint!($($Name),*);
+
+        $crate::make_disjoint_markers!(@aliases $vis [ $($Name),* ] $( $($aliases)+ )?);
+    };
+
+    // with derives, the template receives attributes, so `Reflect` can be added with the `reflect` feature
+    ($type_template_macro:ident, derive ( $($derive:tt)* ) $(, $(#[$common:meta])+ )? for $($names:tt)*) => {
+        $crate::__private::reflect_markers!(
+            [] [ #[derive( $($derive)* )] $( $(#[$common])+ )? ] [ $($derive)* ] $type_template_macro for $($names)*
+        );
+    };
+
+    ($type_template_macro:ident $(, $(#[$common:meta])+ )? for $($names:tt)*) => {
+        $crate::make_disjoint_markers!(
+            @markers [] [ $( $(#[$common])+ )? ] $type_template_macro for $($names)*
+        );
+    };
+
+    ($vis:vis $type_template_macro:ident, derive ( $($derive:tt)* ) $(, $(#[$common:meta])+ )? for $($names:tt)*) => {
+        $crate::__private::reflect_markers!(
+            [ $vis ] [ #[derive( $($derive)* )] $( $(#[$common])+ )? ] [ $($derive)* ] $type_template_macro for $($names)*
+        );
+    };
+
+    ($vis:vis $type_template_macro:ident $(, $(#[$common:meta])+ )? for $($names:tt)*) => {
+        $crate::make_disjoint_markers!(
+            @markers [ $vis ] [ $( $(#[$common])+ )? ] $type_template_macro for $($names)*
+        );
     };
 
     ( $($invalid_input:tt)* ) => {
-        const _: () = panic!(
-            concat!(
+        ::core::compile_error!(
+            ::core::concat!(
                 "Invalid input `",
-                stringify!($($invalid_input)*),
+                ::core::stringify!($($invalid_input)*),
                 "` to macro `make_disjoint_markers!`. Use the form `make_disjoint_markers!(type_template for A, B)` where `type_template` is the name of the macro"
             )
         );
@@ -0,0 +1,80 @@
+use bevy_ecs::{component::Component, entity::Entity, world::World};
+use bevy_utils::tracing::warn;
+
+use crate::{commands::set_variant_index, visitors::has_member, DefaultMembers, DisjointGroup};
+
+/// A system that replaces an existing component `C`, e.g. an enum `OldKind` from before adopting this crate,
+/// with the "variant" of the group `G` at the [`INDEX`](crate::Disjoint::INDEX) returned by `map`.
+///
+/// Every other "variant" is removed, as with [`set_variant`](crate::DisjointEntityCommandsExt::set_variant),
+/// and `C` is removed too if `remove_old` is `true`. Entities whose index has no "variant" are skipped with a warning,
+/// and keep `C`. Run it once, e.g. in `Startup` or with `RunSystemOnce` after loading a save.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::{Component, World};
+/// # use bevy::ecs::system::RunSystemOnce;
+/// use bevy_djqf::{disjoint, migrate_from_enum};
+///
+/// #[derive(Component)]
+/// enum OldKind {
+///     Player,
+///     Enemy,
+/// }
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Player;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Enemy;
+///
+/// disjoint!(Player, Enemy);
+///
+/// let mut world = World::new();
+/// let player = world.spawn(OldKind::Player).id();
+/// let enemy = world.spawn(OldKind::Enemy).id();
+///
+/// world.run_system_once(migrate_from_enum::<OldKind, Player>(
+///     |kind| match kind {
+///         OldKind::Player => 0,
+///         OldKind::Enemy => 1,
+///     },
+///     true,
+/// ));
+///
+/// assert!(world.entity(player).contains::<Player>());
+/// assert!(world.entity(enemy).contains::<Enemy>());
+/// assert!(!world.entity(enemy).contains::<OldKind>());
+/// ```
+pub fn migrate_from_enum<C, G>(
+    map: impl Fn(&C) -> usize + Send + Sync + 'static,
+    remove_old: bool,
+) -> impl FnMut(&mut World) + Send + Sync + 'static
+where
+    C: Component,
+    G: DisjointGroup,
+    G: DefaultMembers,
+{
+    move |world: &mut World| {
+        let entities = world
+            .query::<(Entity, &C)>()
+            .iter(world)
+            .map(|(entity, old)| (entity, map(old)))
+            .collect::<Vec<_>>();
+        for (entity, index) in entities {
+            if !has_member::<G>(index) {
+                warn!(
+                    "Entity {entity:?} was not migrated from `{}`: there is no \"variant\" at index {index}, of the {} indices of the group",
+                    std::any::type_name::<C>(),
+                    G::COUNT
+                );
+                continue;
+            }
+            let mut entity = world.entity_mut(entity);
+            set_variant_index::<G>(&mut entity, Some(index));
+            if remove_old {
+                entity.remove::<C>();
+            }
+        }
+    }
+}
@@ -0,0 +1,167 @@
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    query::QueryFilter,
+    system::{assert_is_system, Query},
+    world::{EntityWorldMut, World},
+};
+
+use crate::{visitors::has_member, DefaultMembers, Disjoint, MemberVisitor};
+
+/// Check that the filters of every "variant" of the group `G` match the entities they should,
+/// by spawning one entity for every combination of "variants", and panic with the name of the filter if one doesn't.
+///
+/// This is [`assert_group_partitions!`](crate::assert_group_partitions), see there for an example.
+///
+/// # Panics
+/// Panics if a filter doesn't match the expected entities, or if the group has more than 20 "variants".
+///
+/// A group of `n` "variants" spawns `2^n` entities, and runs `5n` queries over all of them,
+/// e.g. about a thousand entities for 10 "variants", but about a million for 20.
+pub fn check_partitions<G: DefaultMembers>() {
+    assert!(
+        G::COUNT <= 20,
+        "checking the partitions of a group of {} \"variants\" would spawn 2^{} entities",
+        G::COUNT,
+        G::COUNT
+    );
+
+    // one entity for every combination of members, including none, paired with its combination as a bitmask,
+    // skipping the bits of indices without a member
+    let members = (0..G::COUNT)
+        .filter(|&index| has_member::<G>(index))
+        .fold(0u32, |members, index| members | 1 << index);
+    let mut world = World::new();
+    let entities = (0..1u32 << G::COUNT)
+        .filter(|mask| mask & !members == 0)
+        .map(|mask| {
+            let mut entity = world.spawn_empty();
+            insert_members::<G>(&mut entity, mask);
+            (entity.id(), mask)
+        })
+        .collect::<Vec<_>>();
+
+    let mut check = CheckMember {
+        world: &mut world,
+        entities: &entities,
+        only_or_none: Vec::new(),
+        none: None,
+    };
+    G::for_each_member(&mut check);
+
+    // the `Only` filters of every member, and `None`, match each entity with fewer than two members exactly once
+    let mut only_or_none = check.only_or_none;
+    only_or_none.extend(check.none.unwrap_or_default());
+    only_or_none.sort();
+    assert_eq!(
+        only_or_none,
+        expected(&entities, |mask| mask.count_ones() < 2),
+        "every Only, and None"
+    );
+}
+
+/// Check that a system querying the "variant" `A` with its `Only` filter, and one querying `B`, never conflict,
+/// by building a system with both queries and letting Bevy check its access.
+///
+/// This is [`assert_systems_disjoint!`](crate::assert_systems_disjoint) for two "variants", see there for an example.
+///
+/// # Panics
+/// Panics, with Bevy's conflicting access error, if an entity could match both queries,
+/// i.e. if `A` and `B` are the same "variant", or are from different groups.
+pub fn check_systems_disjoint<A: Disjoint + 'static, B: Disjoint + 'static>() {
+    assert_is_system(
+        |_a: Query<&mut Probe, <A as Disjoint>::Only>,
+         _b: Query<&mut Probe, <B as Disjoint>::Only>| {},
+    );
+}
+
+/// A component that only exists for the queries of [`check_systems_disjoint`] to mutably access.
+#[derive(Component)]
+struct Probe;
+
+fn insert_members<G: DefaultMembers>(entity: &mut EntityWorldMut, mask: u32) {
+    for index in 0..G::COUNT {
+        if mask & (1 << index) != 0 {
+            G::insert(entity, index);
+        }
+    }
+}
+
+/// Checks the filters of each member, collecting what `Only` and `None` match.
+struct CheckMember<'a> {
+    world: &'a mut World,
+    entities: &'a [(Entity, u32)],
+    only_or_none: Vec<Entity>,
+    none: Option<Vec<Entity>>,
+}
+
+impl MemberVisitor for CheckMember<'_> {
+    fn visit<M: Disjoint + Component>(&mut self) {
+        let bit = 1 << M::INDEX;
+        let only = matching::<M::Only>(self.world);
+        let other = matching::<M::Other>(self.world);
+        let any = matching::<M::Any>(self.world);
+        let not = matching::<M::Not>(self.world);
+        let none = matching::<M::None>(self.world);
+
+        let entities = self.entities;
+        assert_eq!(
+            only,
+            expected(entities, |mask| mask == bit),
+            "{} Only",
+            M::NAME
+        );
+        assert_eq!(
+            other,
+            expected(entities, |mask| mask != 0 && mask & bit == 0),
+            "{} Other",
+            M::NAME
+        );
+        assert_eq!(any, expected(entities, |mask| mask != 0), "{} Any", M::NAME);
+        assert_eq!(
+            not,
+            expected(entities, |mask| mask & bit == 0),
+            "{} Not",
+            M::NAME
+        );
+        assert_eq!(
+            none,
+            expected(entities, |mask| mask == 0),
+            "{} None",
+            M::NAME
+        );
+
+        // `Only`, `Other`, `None`, and having `M` along with other members, partition every entity
+        let with_others = expected(entities, |mask| mask & bit != 0 && mask != bit);
+        let mut partition = [only.clone(), other, none.clone(), with_others].concat();
+        partition.sort();
+        assert_eq!(
+            partition,
+            expected(entities, |_| true),
+            "{} partition",
+            M::NAME
+        );
+
+        self.only_or_none.extend(only);
+        self.none.get_or_insert(none);
+    }
+}
+
+fn matching<F: QueryFilter>(world: &mut World) -> Vec<Entity> {
+    let mut entities = world
+        .query_filtered::<Entity, F>()
+        .iter(world)
+        .collect::<Vec<_>>();
+    entities.sort();
+    entities
+}
+
+fn expected(entities: &[(Entity, u32)], predicate: impl Fn(u32) -> bool) -> Vec<Entity> {
+    let mut entities = entities
+        .iter()
+        .filter(|(_, mask)| predicate(*mask))
+        .map(|(entity, _)| *entity)
+        .collect::<Vec<_>>();
+    entities.sort();
+    entities
+}
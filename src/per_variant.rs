@@ -0,0 +1,134 @@
+use crate::{DisjointGroup, GroupMember};
+
+/// One value of `T` for each "variant" of the group `G`, e.g. a count, a color or a config per "variant".
+///
+/// Values are looked up by the "variant" type, with [`get`](Self::get) and [`get_mut`](Self::get_mut),
+/// which only accept members of `G`, so a lookup can never be out of bounds.
+/// They are stored inline in a [`DisjointGroup::Array`], e.g. `[T; 3]` for a group of three "variants",
+/// which [`into_array`](Self::into_array) returns. With explicit indices, the slot of an index without a "variant"
+/// holds a value that no "variant" looks up.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::Component;
+/// use bevy_djqf::{disjoint, PerVariant};
+///
+/// #[derive(Component, Debug, Default)]
+/// struct A;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct B;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct C;
+///
+/// disjoint!(A, B, C);
+///
+/// let mut counts = PerVariant::<A, usize>::from_fn(|index| index * 10);
+/// *counts.get_mut::<C>() += 1;
+///
+/// assert_eq!(*counts.get::<B>(), 10);
+/// assert_eq!(*counts.get::<C>(), 21);
+/// assert_eq!(counts.as_slice(), [0, 10, 21]);
+///
+/// let counts: [usize; 3] = counts.into_array();
+/// assert_eq!(counts, [0, 10, 21]);
+/// ```
+///
+/// Types that aren't members of the group are rejected:
+/// ```compile_fail
+/// # use bevy::prelude::Component;
+/// use bevy_djqf::{disjoint, PerVariant};
+///
+/// #[derive(Component, Debug, Default)]
+/// struct A;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct B;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct X;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Y;
+///
+/// disjoint!(A, B);
+/// disjoint!(X, Y);
+///
+/// let counts = PerVariant::<A, usize>::default();
+/// counts.get::<X>();
+/// ```
+pub struct PerVariant<G: DisjointGroup, T> {
+    values: G::Array<T>,
+}
+
+impl<G: DisjointGroup, T> PerVariant<G, T> {
+    /// Create the values by calling `f` with the [`INDEX`](crate::Disjoint::INDEX) of each "variant", in order.
+    pub fn from_fn(f: impl FnMut(usize) -> T) -> Self {
+        Self {
+            values: VariantArray::from_fn(f),
+        }
+    }
+
+    /// Create the values from an array in the order of the "variants".
+    pub fn from_array(values: G::Array<T>) -> Self {
+        Self { values }
+    }
+
+    /// The value for the "variant" `V`.
+    pub fn get<V: GroupMember<G>>(&self) -> &T {
+        &self.values.as_ref()[V::INDEX]
+    }
+
+    /// The value for the "variant" `V`, mutably.
+    pub fn get_mut<V: GroupMember<G>>(&mut self) -> &mut T {
+        &mut self.values.as_mut()[V::INDEX]
+    }
+
+    /// Every value, in the order of the "variants".
+    pub fn as_slice(&self) -> &[T] {
+        self.values.as_ref()
+    }
+
+    /// Every value, mutably, in the order of the "variants".
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        self.values.as_mut()
+    }
+
+    /// Every value as an array, in the order of the "variants".
+    pub fn into_array(self) -> G::Array<T> {
+        self.values
+    }
+}
+
+impl<G: DisjointGroup, T: Default> Default for PerVariant<G, T> {
+    fn default() -> Self {
+        Self::from_fn(|_| T::default())
+    }
+}
+
+impl<G: DisjointGroup, T: Clone> Clone for PerVariant<G, T> {
+    fn clone(&self) -> Self {
+        Self::from_fn(|index| self.as_slice()[index].clone())
+    }
+}
+
+impl<G: DisjointGroup, T: std::fmt::Debug> std::fmt::Debug for PerVariant<G, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("PerVariant").field(&self.as_slice()).finish()
+    }
+}
+
+/// An array with one `T` for each "variant" of a group, implemented for every `[T; N]`.
+///
+/// This is the bound on [`DisjointGroup::Array`], so that generic code can create and index the array.
+pub trait VariantArray<T>: AsRef<[T]> + AsMut<[T]> + IntoIterator<Item = T> {
+    /// Create the array by calling `f` with each index, in order.
+    fn from_fn(f: impl FnMut(usize) -> T) -> Self;
+}
+
+impl<T, const N: usize> VariantArray<T> for [T; N] {
+    fn from_fn(f: impl FnMut(usize) -> T) -> Self {
+        std::array::from_fn(f)
+    }
+}
@@ -0,0 +1,822 @@
+use std::marker::PhantomData;
+
+use bevy_ecs::{
+    archetype::{Archetype, Archetypes},
+    component::{ComponentId, Components, Tick},
+    entity::{Entities, Entity},
+    query::{
+        FilteredAccess, QueryData, QueryFilter, QueryState, ROQueryItem, ReadOnlyQueryData,
+        WorldQuery,
+    },
+    storage::{Table, TableRow},
+    system::{Query, SystemParam},
+    world::{unsafe_world_cell::UnsafeWorldCell, World},
+};
+
+use crate::{
+    visitors::{has_member, init_member_ids, member_ids},
+    Disjoint, DisjointEnum, DisjointGroup,
+};
+
+/// A [`SystemParam`] for looking up which "variant" of the group `G` an entity has.
+///
+/// `G` is any [`DisjointGroup`], e.g. the first type provided to the [`disjoint!`](crate::disjoint) macro.
+///
+/// This only reads which components entities have, not the components themselves,
+/// so it doesn't conflict with any other queries.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::{Component, Entity, World};
+/// # use bevy::ecs::system::RunSystemOnce;
+/// use bevy_djqf::{disjoint, DisjointQuery};
+///
+/// #[derive(Component, Debug, Default)]
+/// struct A;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct B;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct C;
+///
+/// disjoint!(A, B, C);
+///
+/// let mut world = World::new();
+/// let entities = [
+///     world.spawn(C).id(),
+///     world.spawn(A).id(),
+///     world.spawn_empty().id(),
+///     world.spawn(B).id(),
+/// ];
+///
+/// let variants = world.run_system_once(move |query: DisjointQuery<A>| {
+///     entities.map(|entity| query.variant(entity))
+/// });
+/// assert_eq!(variants, [Some(2), Some(0), None, Some(1)]);
+/// ```
+#[derive(SystemParam)]
+pub struct DisjointQuery<'w, 's, G: DisjointGroup + 'static> {
+    // the state of `VariantOf` holds the `ComponentId` of every member, so they are only looked up once,
+    // and the filter restricts the query to the archetypes with a member
+    query: Query<'w, 's, (Entity, VariantOf<G>), AnyVariantOf<G>>,
+}
+
+impl<G: DisjointGroup + 'static> DisjointQuery<'_, '_, G> {
+    /// The [`INDEX`](crate::Disjoint::INDEX) of the "variant" the entity has,
+    /// or `None` if it has none (or doesn't exist).
+    ///
+    /// If the entity has more than one "variant", this is the first of them.
+    pub fn variant(&self, entity: Entity) -> Option<usize> {
+        self.query.get(entity).ok()?.1
+    }
+
+    /// Every entity with a "variant", paired with the [`INDEX`](Disjoint::INDEX) of that "variant",
+    /// in no particular order.
+    ///
+    /// This is a single query, rather than one query per "variant", which only visits the archetypes with a "variant",
+    /// and finds the "variant" once per archetype.
+    /// As with [`variant`](Self::variant), an entity with more than one "variant" is only paired with the first of them.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::{Component, Entity, World};
+    /// # use bevy::ecs::system::RunSystemOnce;
+    /// use bevy_djqf::{disjoint, Disjoint, DisjointQuery, PerVariant};
+    ///
+    /// #[derive(Component, Debug, Default)]
+    /// struct A;
+    ///
+    /// #[derive(Component, Debug, Default)]
+    /// struct B;
+    ///
+    /// #[derive(Component, Debug, Default)]
+    /// struct C;
+    ///
+    /// disjoint!(A, B, C);
+    ///
+    /// let mut world = World::new();
+    /// let a = world.spawn(A).id();
+    /// let b = [world.spawn(B).id(), world.spawn(B).id()];
+    /// world.spawn_empty();
+    ///
+    /// let partition = world.run_system_once(|query: DisjointQuery<A>| {
+    ///     let mut partition = PerVariant::<A, Vec<Entity>>::default();
+    ///     for (index, entity) in query.iter_by_variant() {
+    ///         partition.as_mut_slice()[index].push(entity);
+    ///     }
+    ///     partition
+    /// });
+    /// assert_eq!(partition.get::<A>(), &[a]);
+    /// assert_eq!(partition.get::<B>(), &b);
+    /// assert!(partition.get::<C>().is_empty());
+    /// ```
+    pub fn iter_by_variant(&self) -> impl Iterator<Item = (usize, Entity)> + '_ {
+        // only the archetypes with a member match, and `VariantOf` finds which member once per archetype
+        self.query
+            .iter()
+            .filter_map(|(entity, index)| Some((index?, entity)))
+    }
+}
+
+/// A [`SystemParam`] for looking up the "variant" of an entity as a value of the [`DisjointEnum`] `E`,
+/// e.g. an enum with [`#[derive(Disjoint)]`](derive@crate::Disjoint), so it can be matched on.
+///
+/// This is [`DisjointQuery`] for the group of `E`, with [`from_index`](DisjointEnum::from_index) applied.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::{Entity, World};
+/// # use bevy::ecs::system::RunSystemOnce;
+/// use bevy_djqf::VariantReader;
+///
+/// #[derive(bevy_djqf::Disjoint)]
+/// enum GameObject {
+///     Player,
+///     Enemy,
+///     Wall,
+/// }
+///
+/// let mut world = World::new();
+/// let entities = [
+///     world.spawn(Enemy).id(),
+///     world.spawn(Player).id(),
+///     world.spawn_empty().id(),
+///     world.spawn(Wall).id(),
+/// ];
+///
+/// let descriptions = world.run_system_once(move |reader: VariantReader<GameObject>| {
+///     entities.map(|entity| match reader.get(entity) {
+///         Some(GameObject::Player) => "player",
+///         Some(GameObject::Enemy) => "enemy",
+///         Some(GameObject::Wall) => "wall",
+///         None => "nothing",
+///     })
+/// });
+/// assert_eq!(descriptions, ["enemy", "player", "nothing", "wall"]);
+/// ```
+#[derive(SystemParam)]
+pub struct VariantReader<'w, 's, E: DisjointEnum + 'static>
+where
+    <E as DisjointEnum>::Group: 'static,
+{
+    query: DisjointQuery<'w, 's, <E as DisjointEnum>::Group>,
+    _enum: PhantomData<fn() -> E>,
+}
+
+impl<E: DisjointEnum + 'static> VariantReader<'_, '_, E>
+where
+    E::Group: 'static,
+{
+    /// The "variant" the entity has, or `None` if it has none (or doesn't exist).
+    ///
+    /// If the entity has more than one "variant", this is the first of them.
+    pub fn get(&self, entity: Entity) -> Option<E> {
+        self.query.variant(entity).and_then(E::from_index)
+    }
+}
+
+/// A [`SystemParam`] holding two queries, one for the "variant" `A` and one for the "variant" `B`,
+/// filtered by [`<A as Disjoint>::Only`](Disjoint::Only) and [`<B as Disjoint>::Only`](Disjoint::Only).
+///
+/// `A` and `B` must be different members of the same group, so the two queries never match the same entity,
+/// and may both access the same components mutably, without a [`ParamSet`](bevy_ecs::system::ParamSet).
+///
+/// `DA` is the [`QueryData`] for `A`, and `DB` is the [`QueryData`] for `B`, which defaults to `DA`.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::{App, Component, Sprite, Transform, Update};
+/// use bevy_djqf::{disjoint, DisjointPair};
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Player;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct InanimateObject;
+///
+/// disjoint!(Player, InanimateObject);
+///
+/// fn movement(
+///     mut pair: DisjointPair<Player, InanimateObject, (&mut Transform, &Sprite), (&Transform, &mut Sprite)>,
+/// ) {
+///     let (players, walls) = pair.queries();
+///     for (mut p_transform, _p_sprite) in players.iter_mut() {
+///         for (w_transform, mut _w_sprite) in walls.iter_mut() {
+///             p_transform.translation = w_transform.translation;
+///         }
+///     }
+/// }
+///
+/// # App::new().add_systems(Update, movement).update();
+/// ```
+///
+/// The "variants" must be from the same group:
+/// ```compile_fail
+/// # use bevy::prelude::{App, Component, Transform, Update};
+/// use bevy_djqf::{disjoint, DisjointPair};
+///
+/// #[derive(Component, Debug, Default)]
+/// struct A;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct B;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct X;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Y;
+///
+/// disjoint!(A, B);
+/// disjoint!(X, Y);
+///
+/// fn system(_pair: DisjointPair<A, X, &mut Transform>) {}
+///
+/// # App::new().add_systems(Update, system).update();
+/// ```
+#[derive(SystemParam)]
+pub struct DisjointPair<'w, 's, A, B, DA, DB = DA>
+where
+    A: Disjoint + 'static,
+    B: Disjoint<Group = <A as Disjoint>::Group> + 'static,
+    <A as Disjoint>::Only: QueryFilter + 'static,
+    <B as Disjoint>::Only: QueryFilter + 'static,
+    DA: QueryData + 'static,
+    DB: QueryData + 'static,
+{
+    a: Query<'w, 's, DA, <A as Disjoint>::Only>,
+    b: Query<'w, 's, DB, <B as Disjoint>::Only>,
+}
+
+impl<'w, 's, A, B, DA, DB> DisjointPair<'w, 's, A, B, DA, DB>
+where
+    A: Disjoint + 'static,
+    B: Disjoint<Group = <A as Disjoint>::Group> + 'static,
+    <A as Disjoint>::Only: QueryFilter + 'static,
+    <B as Disjoint>::Only: QueryFilter + 'static,
+    DA: QueryData + 'static,
+    DB: QueryData + 'static,
+{
+    const DISTINCT: () = assert!(
+        A::INDEX != B::INDEX,
+        "`DisjointPair` requires two different members of the group"
+    );
+
+    /// The query for entities with only the "variant" `A`.
+    pub fn a(&mut self) -> &mut Query<'w, 's, DA, <A as Disjoint>::Only> {
+        let () = Self::DISTINCT;
+        &mut self.a
+    }
+
+    /// The query for entities with only the "variant" `B`.
+    pub fn b(&mut self) -> &mut Query<'w, 's, DB, <B as Disjoint>::Only> {
+        let () = Self::DISTINCT;
+        &mut self.b
+    }
+
+    /// Both queries at once, e.g. to iterate over one while iterating over the other.
+    #[allow(clippy::type_complexity)]
+    pub fn queries(
+        &mut self,
+    ) -> (
+        &mut Query<'w, 's, DA, <A as Disjoint>::Only>,
+        &mut Query<'w, 's, DB, <B as Disjoint>::Only>,
+    ) {
+        let () = Self::DISTINCT;
+        (&mut self.a, &mut self.b)
+    }
+}
+
+/// A [`SystemParam`] for iterating over the entities with any "variant" of the group `G`,
+/// which panics if an entity has more than one "variant", in debug builds.
+///
+/// This is a [`Query`] for `D` filtered by [`AnyVariantOf<G>`], which is [`Any`](Disjoint::Any) for any [`DisjointGroup`],
+/// including a struct group from [`disjoint_groups!`](crate::disjoint_groups), useful for catching data bugs during development.
+/// In release builds, it doesn't check anything.
+///
+/// # Example
+/// ```should_panic
+/// # use bevy::prelude::{Component, World};
+/// # use bevy::ecs::system::RunSystemOnce;
+/// use bevy_djqf::{disjoint, ExclusiveVariantQuery};
+///
+/// #[derive(Component, Debug, Default)]
+/// struct A;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct B;
+///
+/// disjoint!(A, B);
+///
+/// let mut world = World::new();
+/// world.spawn(A);
+/// world.spawn((A, B));
+///
+/// // panics: the second entity has both `A` and `B`
+/// world.run_system_once(|query: ExclusiveVariantQuery<A>| query.iter().count());
+/// ```
+#[derive(SystemParam)]
+pub struct ExclusiveVariantQuery<'w, 's, G, D = Entity>
+where
+    G: DisjointGroup + 'static,
+    D: QueryData + 'static,
+{
+    query: Query<'w, 's, (Entity, D), AnyVariantOf<G>>,
+    archetypes: &'w Archetypes,
+    components: &'w Components,
+    entities: &'w Entities,
+}
+
+impl<'w, 's, G, D> ExclusiveVariantQuery<'w, 's, G, D>
+where
+    G: DisjointGroup + 'static,
+    D: QueryData + 'static,
+{
+    /// Iterate over the read-only query items, checking each entity.
+    pub fn iter(&self) -> impl Iterator<Item = ROQueryItem<'_, D>> + '_ {
+        let check = self.checker();
+        self.query.iter().map(move |(entity, item)| {
+            check(entity);
+            item
+        })
+    }
+
+    /// Iterate over the query items, checking each entity.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = D::Item<'_>> + '_ {
+        let check = self.checker();
+        self.query.iter_mut().map(move |(entity, item)| {
+            check(entity);
+            item
+        })
+    }
+
+    fn checker(&self) -> impl Fn(Entity) + 'w {
+        let members = if cfg!(debug_assertions) {
+            member_ids::<G>(self.components)
+        } else {
+            Vec::new()
+        };
+        let archetypes = self.archetypes;
+        let entities = self.entities;
+        move |entity| {
+            // nothing to check in release builds
+            if members.is_empty() {
+                return;
+            }
+            let Some(archetype) = entities
+                .get(entity)
+                .and_then(|location| archetypes.get(location.archetype_id))
+            else {
+                return;
+            };
+            let mut present = members
+                .iter()
+                .filter(|(_, id)| id.is_some_and(|id| archetype.contains(id)))
+                .map(|(name, _)| *name);
+            if let (Some(first), Some(second)) = (present.next(), present.next()) {
+                panic!("Entity {entity:?} has both `{first}` and `{second}`, which are disjoint");
+            }
+        }
+    }
+}
+
+/// A [`QueryData`] for the [`INDEX`](Disjoint::INDEX) of the "variant" of the group `G` each entity has,
+/// or `None` if it has none, like [`Has`](bevy_ecs::query::Has) for a whole group.
+///
+/// This is [`DisjointQuery::variant`] inside a regular query, alongside other components.
+/// As there, an entity with more than one "variant" yields the first of them.
+/// It doesn't filter the query, and only reads which components entities have,
+/// so it doesn't conflict with any other queries.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::{Component, Query, Transform, World};
+/// # use bevy::ecs::system::RunSystemOnce;
+/// use bevy_djqf::{disjoint, VariantOf};
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Player;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Enemy;
+///
+/// disjoint!(Player, Enemy);
+///
+/// let mut world = World::new();
+/// world.spawn((Transform::from_xyz(1.0, 0.0, 0.0), Enemy));
+/// world.spawn((Transform::from_xyz(2.0, 0.0, 0.0), Player));
+/// world.spawn(Transform::from_xyz(3.0, 0.0, 0.0));
+///
+/// let mut variants = world.run_system_once(|query: Query<(&Transform, VariantOf<Player>)>| {
+///     query
+///         .iter()
+///         .map(|(transform, variant)| (transform.translation.x, variant))
+///         .collect::<Vec<_>>()
+/// });
+/// variants.sort_by(|a, b| a.0.total_cmp(&b.0));
+/// assert_eq!(variants, [(1.0, Some(1)), (2.0, Some(0)), (3.0, None)]);
+/// ```
+pub struct VariantOf<G>(PhantomData<fn() -> G>);
+
+impl<G> std::fmt::Debug for VariantOf<G> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "VariantOf<{}>", std::any::type_name::<G>())
+    }
+}
+
+/// SAFETY:
+/// `update_component_access` only adds archetypal access, as `Has` does.
+/// This is sound because `fetch` does not access components.
+unsafe impl<G: DisjointGroup> WorldQuery for VariantOf<G> {
+    type Item<'w> = Option<usize>;
+    type Fetch<'w> = Option<usize>;
+    // the id at each index, or `None` for an index without a member
+    type State = Box<[Option<ComponentId>]>;
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::Item<'wlong>) -> Self::Item<'wshort> {
+        item
+    }
+
+    #[inline]
+    unsafe fn init_fetch<'w>(
+        _world: UnsafeWorldCell<'w>,
+        _state: &Self::State,
+        _last_run: Tick,
+        _this_run: Tick,
+    ) -> Self::Fetch<'w> {
+        None
+    }
+
+    // members may be stored in sparse sets, which tables don't know about
+    const IS_DENSE: bool = false;
+
+    #[inline]
+    unsafe fn set_archetype<'w>(
+        fetch: &mut Self::Fetch<'w>,
+        state: &Self::State,
+        archetype: &'w Archetype,
+        _table: &Table,
+    ) {
+        *fetch = state
+            .iter()
+            .position(|id| id.is_some_and(|id| archetype.contains(id)));
+    }
+
+    #[inline]
+    unsafe fn set_table<'w>(fetch: &mut Self::Fetch<'w>, state: &Self::State, table: &'w Table) {
+        *fetch = state
+            .iter()
+            .position(|id| id.is_some_and(|id| table.has_column(id)));
+    }
+
+    #[inline(always)]
+    unsafe fn fetch<'w>(
+        fetch: &mut Self::Fetch<'w>,
+        _entity: Entity,
+        _table_row: TableRow,
+    ) -> Self::Item<'w> {
+        *fetch
+    }
+
+    fn update_component_access(state: &Self::State, access: &mut FilteredAccess<ComponentId>) {
+        for &id in state.iter().flatten() {
+            access.access_mut().add_archetypal(id);
+        }
+    }
+
+    fn init_state(world: &mut World) -> Self::State {
+        init_member_ids::<G>(world).into()
+    }
+
+    fn get_state(components: &Components) -> Option<Self::State> {
+        member_ids::<G>(components)
+            .into_iter()
+            .enumerate()
+            .map(|(index, (_, id))| {
+                if has_member::<G>(index) {
+                    id.map(Some)
+                } else {
+                    Some(None)
+                }
+            })
+            .collect()
+    }
+
+    fn matches_component_set(
+        _state: &Self::State,
+        _set_contains_id: &impl Fn(ComponentId) -> bool,
+    ) -> bool {
+        // like `Has`, this always matches
+        true
+    }
+}
+
+/// SAFETY: `Self` is the same as `Self::ReadOnly`
+unsafe impl<G: DisjointGroup> QueryData for VariantOf<G> {
+    type ReadOnly = Self;
+}
+
+/// SAFETY: [`VariantOf`] is read only
+unsafe impl<G: DisjointGroup> ReadOnlyQueryData for VariantOf<G> {}
+
+/// A [`QueryFilter`] for entities with any "variant" of the group `G`, like [`Any`](Disjoint::Any),
+/// for any [`DisjointGroup`], including a struct group from [`disjoint_groups!`](crate::disjoint_groups), which has no `Any`.
+///
+/// Like `Any`, it is matched once per archetype, so the query only visits the archetypes with a "variant".
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::{Component, Entity, World};
+/// use bevy_djqf::{disjoint_groups, AnyVariantOf};
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Player;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Enemy;
+///
+/// disjoint_groups! {
+///     struct GameObject { Player, Enemy },
+/// }
+///
+/// let mut world = World::new();
+/// let mut entities = [world.spawn(Player).id(), world.spawn(Enemy).id()];
+/// world.spawn_empty();
+///
+/// let mut query = world.query_filtered::<Entity, AnyVariantOf<GameObject>>();
+/// let mut matched = query.iter(&world).collect::<Vec<_>>();
+/// matched.sort();
+/// entities.sort();
+/// assert_eq!(matched, entities);
+/// ```
+pub struct AnyVariantOf<G>(PhantomData<fn() -> G>);
+
+impl<G> std::fmt::Debug for AnyVariantOf<G> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "AnyVariantOf<{}>", std::any::type_name::<G>())
+    }
+}
+
+/// SAFETY:
+/// `update_component_access` only adds `With` filters, as `Or<(With<A>, With<B>, ...)>` does.
+/// This is sound because `fetch` does not access components.
+unsafe impl<G: DisjointGroup> WorldQuery for AnyVariantOf<G> {
+    type Item<'w> = ();
+    type Fetch<'w> = ();
+    // the id of every member, without the indices that have none
+    type State = Box<[ComponentId]>;
+
+    fn shrink<'wlong: 'wshort, 'wshort>(_item: Self::Item<'wlong>) -> Self::Item<'wshort> {}
+
+    #[inline]
+    unsafe fn init_fetch<'w>(
+        _world: UnsafeWorldCell<'w>,
+        _state: &Self::State,
+        _last_run: Tick,
+        _this_run: Tick,
+    ) -> Self::Fetch<'w> {
+    }
+
+    // members may be stored in sparse sets, which tables don't know about
+    const IS_DENSE: bool = false;
+
+    #[inline]
+    unsafe fn set_archetype<'w>(
+        _fetch: &mut Self::Fetch<'w>,
+        _state: &Self::State,
+        _archetype: &'w Archetype,
+        _table: &Table,
+    ) {
+    }
+
+    #[inline]
+    unsafe fn set_table<'w>(_fetch: &mut Self::Fetch<'w>, _state: &Self::State, _table: &'w Table) {
+    }
+
+    #[inline(always)]
+    unsafe fn fetch<'w>(
+        _fetch: &mut Self::Fetch<'w>,
+        _entity: Entity,
+        _table_row: TableRow,
+    ) -> Self::Item<'w> {
+    }
+
+    fn update_component_access(state: &Self::State, access: &mut FilteredAccess<ComponentId>) {
+        // as `Or` does for `With` filters: one filter set for each member
+        let mut new_access = access.clone();
+        for (position, &id) in state.iter().enumerate() {
+            if position == 0 {
+                new_access.and_with(id);
+            } else {
+                let mut intermediate = access.clone();
+                intermediate.and_with(id);
+                new_access.append_or(&intermediate);
+            }
+        }
+        *access = new_access;
+    }
+
+    fn init_state(world: &mut World) -> Self::State {
+        init_member_ids::<G>(world).into_iter().flatten().collect()
+    }
+
+    fn get_state(components: &Components) -> Option<Self::State> {
+        member_ids::<G>(components)
+            .into_iter()
+            .enumerate()
+            .filter(|&(index, _)| has_member::<G>(index))
+            .map(|(_, (_, id))| id)
+            .collect()
+    }
+
+    fn matches_component_set(
+        state: &Self::State,
+        set_contains_id: &impl Fn(ComponentId) -> bool,
+    ) -> bool {
+        state.iter().any(|&id| set_contains_id(id))
+    }
+}
+
+impl<G: DisjointGroup> QueryFilter for AnyVariantOf<G> {
+    const IS_ARCHETYPAL: bool = true;
+
+    #[inline(always)]
+    unsafe fn filter_fetch(
+        _fetch: &mut Self::Fetch<'_>,
+        _entity: Entity,
+        _table_row: TableRow,
+    ) -> bool {
+        true
+    }
+}
+
+/// A [`QueryData`] for the "variant" each entity has as a value of the [`DisjointEnum`] `E`,
+/// e.g. an enum with [`#[derive(Disjoint)]`](derive@crate::Disjoint), so that query items can be matched on,
+/// or `None` if it has none.
+///
+/// This is [`VariantOf`] for the group of `E`, with [`from_index`](DisjointEnum::from_index) applied, and like it,
+/// an entity with more than one "variant" yields the first of them. To skip entities without a "variant"
+/// rather than yield `None`, filter the query with [`Any`](Disjoint::Any), e.g. `GameObjectAny`.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::{Query, Transform, World};
+/// # use bevy::ecs::system::RunSystemOnce;
+/// use bevy_djqf::DisjointData;
+///
+/// #[derive(bevy_djqf::Disjoint)]
+/// enum GameObject {
+///     Player,
+///     Enemy,
+///     Wall,
+/// }
+///
+/// let mut world = World::new();
+/// world.spawn((Transform::from_xyz(1.0, 0.0, 0.0), Enemy));
+/// world.spawn((Transform::from_xyz(2.0, 0.0, 0.0), Player));
+/// world.spawn((Transform::from_xyz(3.0, 0.0, 0.0), Wall));
+/// world.spawn(Transform::from_xyz(4.0, 0.0, 0.0));
+///
+/// let mut descriptions = world.run_system_once(|query: Query<(&Transform, DisjointData<GameObject>)>| {
+///     query
+///         .iter()
+///         .map(|(transform, value)| {
+///             let description = match value {
+///                 Some(GameObject::Player) => "player",
+///                 Some(GameObject::Enemy) => "enemy",
+///                 Some(GameObject::Wall) => "wall",
+///                 None => "nothing",
+///             };
+///             (transform.translation.x, description)
+///         })
+///         .collect::<Vec<_>>()
+/// });
+/// descriptions.sort_by(|a, b| a.0.total_cmp(&b.0));
+/// assert_eq!(descriptions, [(1.0, "enemy"), (2.0, "player"), (3.0, "wall"), (4.0, "nothing")]);
+///
+/// // without `None`
+/// let count = world.run_system_once(|query: Query<DisjointData<GameObject>, GameObjectAny>| {
+///     query.iter().filter(Option::is_some).count()
+/// });
+/// assert_eq!(count, 3);
+/// ```
+pub struct DisjointData<E>(PhantomData<fn() -> E>);
+
+impl<E> std::fmt::Debug for DisjointData<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "DisjointData<{}>", std::any::type_name::<E>())
+    }
+}
+
+/// SAFETY:
+/// Everything but `fetch` is delegated to [`VariantOf`], which only adds archetypal access.
+/// This is sound because `fetch` does not access components either.
+unsafe impl<E: DisjointEnum> WorldQuery for DisjointData<E> {
+    type Item<'w> = Option<E>;
+    type Fetch<'w> = Option<usize>;
+    type State = Box<[Option<ComponentId>]>;
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::Item<'wlong>) -> Self::Item<'wshort> {
+        item
+    }
+
+    #[inline]
+    unsafe fn init_fetch<'w>(
+        world: UnsafeWorldCell<'w>,
+        state: &Self::State,
+        last_run: Tick,
+        this_run: Tick,
+    ) -> Self::Fetch<'w> {
+        VariantOf::<E::Group>::init_fetch(world, state, last_run, this_run)
+    }
+
+    const IS_DENSE: bool = <VariantOf<E::Group> as WorldQuery>::IS_DENSE;
+
+    #[inline]
+    unsafe fn set_archetype<'w>(
+        fetch: &mut Self::Fetch<'w>,
+        state: &Self::State,
+        archetype: &'w Archetype,
+        table: &Table,
+    ) {
+        VariantOf::<E::Group>::set_archetype(fetch, state, archetype, table);
+    }
+
+    #[inline]
+    unsafe fn set_table<'w>(fetch: &mut Self::Fetch<'w>, state: &Self::State, table: &'w Table) {
+        VariantOf::<E::Group>::set_table(fetch, state, table);
+    }
+
+    #[inline(always)]
+    unsafe fn fetch<'w>(
+        fetch: &mut Self::Fetch<'w>,
+        _entity: Entity,
+        _table_row: TableRow,
+    ) -> Self::Item<'w> {
+        fetch.and_then(E::from_index)
+    }
+
+    fn update_component_access(state: &Self::State, access: &mut FilteredAccess<ComponentId>) {
+        VariantOf::<E::Group>::update_component_access(state, access);
+    }
+
+    fn init_state(world: &mut World) -> Self::State {
+        VariantOf::<E::Group>::init_state(world)
+    }
+
+    fn get_state(components: &Components) -> Option<Self::State> {
+        VariantOf::<E::Group>::get_state(components)
+    }
+
+    fn matches_component_set(
+        state: &Self::State,
+        set_contains_id: &impl Fn(ComponentId) -> bool,
+    ) -> bool {
+        VariantOf::<E::Group>::matches_component_set(state, set_contains_id)
+    }
+}
+
+/// SAFETY: `Self` is the same as `Self::ReadOnly`
+unsafe impl<E: DisjointEnum> QueryData for DisjointData<E> {
+    type ReadOnly = Self;
+}
+
+/// SAFETY: [`DisjointData`] is read only
+unsafe impl<E: DisjointEnum> ReadOnlyQueryData for DisjointData<E> {}
+
+/// Create a [`QueryState`] for the entities that only have the "variant" `V`, i.e. with the filter [`Only`](Disjoint::Only),
+/// e.g. to cache it in a [`Resource`](bevy_ecs::system::Resource) or an exclusive system's [`Local`](bevy_ecs::system::Local)
+/// for hot loops in custom schedules.
+///
+/// The returned state is owned by the caller and doesn't borrow the world, so it can be kept across frames.
+/// It is only valid for the `world` it was created from, which must be passed to every call on it,
+/// and it picks up archetypes created after it was built whenever it is iterated.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::{Component, Entity, World};
+/// use bevy_djqf::{disjoint, only_query_state};
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Player;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct Enemy;
+///
+/// disjoint!(Player, Enemy);
+///
+/// let mut world = World::new();
+/// let mut players = only_query_state::<Player, Entity>(&mut world);
+///
+/// let player = world.spawn(Player).id();
+/// world.spawn((Player, Enemy));
+/// assert_eq!(players.iter(&world).collect::<Vec<_>>(), [player]);
+/// ```
+pub fn only_query_state<V, D>(world: &mut World) -> QueryState<D, V::Only>
+where
+    V: Disjoint,
+    D: QueryData,
+{
+    QueryState::new(world)
+}
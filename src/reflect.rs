@@ -0,0 +1,52 @@
+use bevy_app::App;
+
+use crate::DisjointGroup;
+
+/// Extension methods on [`App`] for reflection of disjoint groups. Requires the `reflect` feature.
+pub trait DisjointReflectAppExt {
+    /// Register every "variant" of the group `G` with the `TypeRegistry`, including their `ReflectComponent` type data.
+    ///
+    /// Every "variant" must implement `Reflect`, e.g. with `#[derive(Reflect)]`.
+    /// With the `reflect` feature, [`#[derive(Disjoint)]`](derive@crate::Disjoint) does this for the markers it generates,
+    /// and [`make_disjoint_markers!`](crate::make_disjoint_markers) does for markers with derives,
+    /// e.g. `make_disjoint_markers!(type_template, derive(Component, Default) for A, B)`.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::{App, AppTypeRegistry, Component, Reflect};
+    /// # use bevy::ecs::reflect::ReflectComponent;
+    /// use bevy_djqf::{disjoint, DisjointReflectAppExt};
+    ///
+    /// #[derive(Component, Reflect, Debug, Default)]
+    /// struct A;
+    ///
+    /// #[derive(Component, Reflect, Debug, Default)]
+    /// struct B;
+    ///
+    /// disjoint!(A, B);
+    ///
+    /// let mut app = App::new();
+    /// app.register_disjoint_group::<A>();
+    ///
+    /// let registry = app.world().resource::<AppTypeRegistry>().read();
+    /// assert!(registry.get_type_data::<ReflectComponent>(std::any::TypeId::of::<A>()).is_some());
+    /// assert!(registry.get_type_data::<ReflectComponent>(std::any::TypeId::of::<B>()).is_some());
+    /// ```
+    fn register_disjoint_group<G: ReflectMembers>(&mut self) -> &mut Self;
+}
+
+impl DisjointReflectAppExt for App {
+    fn register_disjoint_group<G: ReflectMembers>(&mut self) -> &mut Self {
+        G::register(self);
+        self
+    }
+}
+
+/// A group whose "variants" can all be reflected.
+///
+/// Implemented by the [`disjoint!`](crate::disjoint) macro, with the `reflect` feature, for every group whose "variants"
+/// all implement `Reflect`, whatever its size.
+pub trait ReflectMembers: DisjointGroup {
+    /// Register every member with the `TypeRegistry`.
+    fn register(app: &mut App);
+}
@@ -0,0 +1,203 @@
+use std::marker::PhantomData;
+
+use bevy_app::App;
+use bevy_ecs::{
+    component::{Component, ComponentId, Components},
+    query::{QueryBuilder, QueryData, QueryFilter},
+    system::Resource,
+};
+
+use crate::DynamicDisjoint;
+
+/// A group of disjoint "variants" that is built at runtime, so that different crates or plugins can each
+/// contribute "variants" to the same group, e.g. to a shared `GameObject` group.
+///
+/// The [`disjoint!`](crate::disjoint) macro needs every member in one place, and Rust's coherence rules
+/// don't allow another crate to add more later. Instead, each plugin registers its own markers
+/// with [`DisjointRegistryAppExt::register_disjoint_member`], and the filters are built from the
+/// [`ComponentId`]s of the registered members with a [`QueryBuilder`], see [`DynamicDisjoint`].
+///
+/// `L` is any type that names the group, e.g. `struct GameObject;`. The members don't implement [`Disjoint`](crate::Disjoint),
+/// and since the filters are only known at runtime, they can't be used in the type of a [`Query`](bevy_ecs::system::Query).
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::{App, Component, Entity, Plugin};
+/// # use bevy::ecs::query::QueryBuilder;
+/// use bevy_djqf::{DisjointRegistry, DisjointRegistryAppExt};
+///
+/// // in a core crate
+/// struct GameObject;
+///
+/// #[derive(Component)]
+/// struct Player;
+///
+/// // in a plugin crate
+/// #[derive(Component)]
+/// struct Goblin;
+///
+/// struct GoblinPlugin;
+///
+/// impl Plugin for GoblinPlugin {
+///     fn build(&self, app: &mut App) {
+///         app.register_disjoint_member::<GameObject, Goblin>();
+///     }
+/// }
+///
+/// let mut app = App::new();
+/// app.register_disjoint_member::<GameObject, Player>();
+/// app.add_plugins(GoblinPlugin);
+///
+/// let world = app.world_mut();
+/// let player = world.spawn(Player).id();
+/// let goblin = world.spawn(Goblin).id();
+/// world.spawn((Player, Goblin));
+/// let untagged = world.spawn_empty().id();
+///
+/// let registry = world.resource::<DisjointRegistry<GameObject>>().clone();
+/// assert_eq!(registry.len(), 2);
+/// let player_id = registry.id::<Player>(world.components()).unwrap();
+///
+/// let mut only_player = QueryBuilder::<Entity>::new(world);
+/// registry.only(&mut only_player, player_id);
+/// let mut only_player = only_player.build();
+/// assert_eq!(only_player.iter(world).collect::<Vec<_>>(), [player]);
+///
+/// let mut other = QueryBuilder::<Entity>::new(world);
+/// registry.other(&mut other, player_id);
+/// let mut other = other.build();
+/// assert_eq!(other.iter(world).collect::<Vec<_>>(), [goblin]);
+///
+/// let mut none = QueryBuilder::<Entity>::new(world);
+/// registry.none(&mut none);
+/// let mut none = none.build();
+/// assert_eq!(none.iter(world).collect::<Vec<_>>(), [untagged]);
+/// ```
+#[derive(Resource)]
+pub struct DisjointRegistry<L> {
+    names: Vec<&'static str>,
+    group: DynamicDisjoint,
+    _label: PhantomData<fn() -> L>,
+}
+
+impl<L> Clone for DisjointRegistry<L> {
+    fn clone(&self) -> Self {
+        Self {
+            names: self.names.clone(),
+            group: self.group.clone(),
+            _label: PhantomData,
+        }
+    }
+}
+
+impl<L> std::fmt::Debug for DisjointRegistry<L> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DisjointRegistry")
+            .field("names", &self.names)
+            .field("group", &self.group)
+            .finish()
+    }
+}
+
+impl<L> Default for DisjointRegistry<L> {
+    fn default() -> Self {
+        Self {
+            names: Vec::new(),
+            group: DynamicDisjoint::default(),
+            _label: PhantomData,
+        }
+    }
+}
+
+impl<L> DisjointRegistry<L> {
+    /// Add a member, unless it was already registered.
+    ///
+    /// Returns its index, which is the order it was registered in, starting from `0`.
+    pub fn register(&mut self, name: &'static str, id: ComponentId) -> usize {
+        let index = self.group.insert(id);
+        if index == self.names.len() {
+            self.names.push(name);
+        }
+        index
+    }
+
+    /// The number of members.
+    pub fn len(&self) -> usize {
+        self.group.len()
+    }
+
+    /// Whether no member has been registered yet.
+    pub fn is_empty(&self) -> bool {
+        self.group.is_empty()
+    }
+
+    /// The index of the member with this [`ComponentId`], if it is a member.
+    pub fn index(&self, id: ComponentId) -> Option<usize> {
+        self.group.index(id)
+    }
+
+    /// The [`ComponentId`] of the component `T`, if it is a member.
+    pub fn id<T: Component>(&self, components: &Components) -> Option<ComponentId> {
+        let id = components.component_id::<T>()?;
+        self.index(id).map(|_| id)
+    }
+
+    /// The name of every member, in the order they were registered.
+    pub fn names(&self) -> &[&'static str] {
+        &self.names
+    }
+
+    /// The members as a [`DynamicDisjoint`], which builds the filters.
+    pub fn group(&self) -> &DynamicDisjoint {
+        &self.group
+    }
+
+    /// Filter the query to entities with the member `id`, and no other member, like [`Only`](crate::Disjoint::Only).
+    pub fn only<D: QueryData, F: QueryFilter>(
+        &self,
+        builder: &mut QueryBuilder<D, F>,
+        id: ComponentId,
+    ) {
+        self.group.only(builder, id);
+    }
+
+    /// Filter the query to entities without the member `id`, but with another member, like [`Other`](crate::Disjoint::Other).
+    pub fn other<D: QueryData, F: QueryFilter>(
+        &self,
+        builder: &mut QueryBuilder<D, F>,
+        id: ComponentId,
+    ) {
+        self.group.other(builder, id);
+    }
+
+    /// Filter the query to entities with any member, like [`Any`](crate::Disjoint::Any).
+    ///
+    /// While there are no members, nothing matches.
+    pub fn any<D: QueryData, F: QueryFilter>(&self, builder: &mut QueryBuilder<D, F>) {
+        self.group.any(builder);
+    }
+
+    /// Filter the query to entities with no member, like [`None`](crate::Disjoint::None).
+    pub fn none<D: QueryData, F: QueryFilter>(&self, builder: &mut QueryBuilder<D, F>) {
+        self.group.none(builder);
+    }
+}
+
+/// Extension methods on [`App`] for contributing "variants" to a [`DisjointRegistry`].
+pub trait DisjointRegistryAppExt {
+    /// Register the component `T` as a member of the [`DisjointRegistry`] `L`, inserting the registry if needed.
+    ///
+    /// Registering the same component twice is not an error. See [`DisjointRegistry`] for an example.
+    fn register_disjoint_member<L: 'static, T: Component>(&mut self) -> &mut Self;
+}
+
+impl DisjointRegistryAppExt for App {
+    fn register_disjoint_member<L: 'static, T: Component>(&mut self) -> &mut Self {
+        let world = self.world_mut();
+        let id = world.init_component::<T>();
+        world
+            .get_resource_or_insert_with(DisjointRegistry::<L>::default)
+            .register(std::any::type_name::<T>(), id);
+        self
+    }
+}
@@ -0,0 +1,133 @@
+use std::{fmt, marker::PhantomData};
+
+use bevy_ecs::{
+    entity::Entity,
+    system::EntityCommand,
+    world::{EntityRef, World},
+};
+use serde::{
+    de::{self, Visitor},
+    Deserializer, Serializer,
+};
+
+use crate::{commands::set_variant_index, visitors::variant_of, DefaultMembers, DisjointGroup};
+
+/// Serialize which "variant" of the group `G` the entity has, as its [`NAME`](crate::Disjoint::NAME),
+/// or as none if it has no "variant". Requires the `serde` feature.
+///
+/// Restore it with [`deserialize_variant`].
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::{Commands, Component, World};
+/// # use bevy::ecs::world::CommandQueue;
+/// use bevy_djqf::{deserialize_variant, disjoint, serialize_variant};
+///
+/// #[derive(Component, Debug, Default)]
+/// struct A;
+///
+/// #[derive(Component, Debug, Default)]
+/// struct B;
+///
+/// disjoint!(A, B);
+///
+/// let mut world = World::new();
+/// let a = world.spawn(A).id();
+/// let empty = world.spawn_empty().id();
+///
+/// let saved_a = serialize_variant::<A, _>(world.entity(a), serde_json::value::Serializer).unwrap();
+/// let saved_empty = serialize_variant::<A, _>(world.entity(empty), serde_json::value::Serializer).unwrap();
+/// assert_eq!(saved_a, serde_json::json!("A"));
+/// assert_eq!(saved_empty, serde_json::Value::Null);
+///
+/// // restore them the other way around
+/// let mut queue = CommandQueue::default();
+/// let mut commands = Commands::new(&mut queue, &world);
+/// commands.entity(a).add(deserialize_variant::<A, _>(saved_empty).unwrap());
+/// commands.entity(empty).add(deserialize_variant::<A, _>(saved_a).unwrap());
+/// queue.apply(&mut world);
+///
+/// assert!(!world.entity(a).contains::<A>());
+/// assert!(!world.entity(a).contains::<B>());
+/// assert!(world.entity(empty).contains::<A>());
+/// ```
+pub fn serialize_variant<G: DisjointGroup, S: Serializer>(
+    entity: EntityRef,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match variant_of::<G>(&entity) {
+        Some(index) => serializer.serialize_some(G::NAMES[index]),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// Deserialize a "variant" of the group `G` that was serialized with [`serialize_variant`]. Requires the `serde` feature.
+///
+/// The result is an [`EntityCommand`] that sets the "variant" exclusively, or removes every "variant" if there was none.
+/// Unknown names are an error.
+pub fn deserialize_variant<'de, G: DisjointGroup, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<ApplyVariant<G>, D::Error> {
+    let index = deserializer.deserialize_option(VariantVisitor::<G>(PhantomData))?;
+    Ok(ApplyVariant {
+        index,
+        _group: PhantomData,
+    })
+}
+
+/// An [`EntityCommand`] that sets the "variant" of the group `G` that was read by [`deserialize_variant`].
+pub struct ApplyVariant<G> {
+    index: Option<usize>,
+    _group: PhantomData<fn() -> G>,
+}
+
+impl<G> ApplyVariant<G> {
+    /// The [`INDEX`](crate::Disjoint::INDEX) of the "variant" that will be set, or `None` if every "variant" will be removed.
+    pub fn index(&self) -> Option<usize> {
+        self.index
+    }
+}
+
+impl<G: DisjointGroup + 'static> EntityCommand for ApplyVariant<G>
+where
+    G: DefaultMembers,
+{
+    fn apply(self, id: Entity, world: &mut World) {
+        if let Some(mut entity) = world.get_entity_mut(id) {
+            set_variant_index::<G>(&mut entity, self.index);
+        }
+    }
+}
+
+struct VariantVisitor<G>(PhantomData<fn() -> G>);
+
+impl<'de, G: DisjointGroup> Visitor<'de> for VariantVisitor<G> {
+    type Value = Option<usize>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "one of {:?}, or none", G::NAMES)
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_str(self)
+    }
+
+    fn visit_str<E: de::Error>(self, name: &str) -> Result<Self::Value, E> {
+        // an index without a "variant" has an empty name, which must not match
+        match G::NAMES
+            .iter()
+            .position(|member| !member.is_empty() && *member == name)
+        {
+            Some(index) => Ok(Some(index)),
+            None => Err(E::unknown_variant(name, G::NAMES)),
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use std::marker::PhantomData;
+
+use bevy_ecs::{
+    change_detection::DetectChanges,
+    component::Component,
+    entity::Entity,
+    system::{Commands, Query, Res},
+    world::Ref,
+};
+use bevy_state::state::{State, States};
+
+use crate::{DefaultMembers, DisjointEntityCommandsExt, DisjointEnum};
+
+/// Marks an entity to have the "variant" matching the current state `S`, see [`mirror_state_to_markers`].
+/// Requires the `states` feature.
+#[derive(Component)]
+pub struct MirrorState<S>(PhantomData<fn() -> S>);
+
+impl<S> Default for MirrorState<S> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+/// A system that gives every entity with [`MirrorState<S>`] the "variant" matching the current state `S`,
+/// and removes every other "variant". Requires the `states` feature.
+///
+/// `S` is a [`States`] enum that is also a [`DisjointEnum`], e.g. with `#[derive(States, Disjoint)]`,
+/// so each state has a marker component.
+///
+/// The markers are set once for the initial state, once for each transition,
+/// and once for each entity when [`MirrorState<S>`] is added to it.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::{App, AppExtStates, NextState, States, Update};
+/// # use bevy::state::app::StatesPlugin;
+/// use bevy_djqf::{mirror_state_to_markers, Disjoint, MirrorState};
+///
+/// #[derive(States, bevy_djqf::Disjoint, Clone, Debug, Default, PartialEq, Eq, Hash)]
+/// enum GameState {
+///     #[default]
+///     Menu,
+///     Playing,
+/// }
+///
+/// let mut app = App::new();
+/// app.add_plugins(StatesPlugin)
+///     .init_state::<GameState>()
+///     .add_systems(Update, mirror_state_to_markers::<GameState>);
+///
+/// let entity = app.world_mut().spawn(MirrorState::<GameState>::default()).id();
+/// app.update();
+/// assert!(app.world().entity(entity).contains::<Menu>());
+///
+/// app.world_mut().resource_mut::<NextState<GameState>>().set(GameState::Playing);
+/// app.update();
+/// assert!(!app.world().entity(entity).contains::<Menu>());
+/// assert!(app.world().entity(entity).contains::<Playing>());
+/// ```
+pub fn mirror_state_to_markers<S>(
+    state: Res<State<S>>,
+    entities: Query<(Entity, Ref<MirrorState<S>>)>,
+    mut commands: Commands,
+) where
+    S: States + DisjointEnum,
+    S::Group: DefaultMembers,
+{
+    let changed = state.is_changed();
+    for (entity, mirror) in &entities {
+        if changed || mirror.is_added() {
+            commands
+                .entity(entity)
+                .set_variant_value(state.get().clone());
+        }
+    }
+}
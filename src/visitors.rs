@@ -0,0 +1,134 @@
+//! [`MemberVisitor`]s shared by the runtime helpers.
+//!
+//! The members are collected at their [`INDEX`](Disjoint::INDEX), so that an index without a member,
+//! from explicit indices with gaps, is an empty slot.
+
+use std::any::TypeId;
+
+use bevy_ecs::{
+    component::{Component, ComponentId, Components},
+    world::{EntityRef, EntityWorldMut, World},
+};
+
+use crate::{Disjoint, DisjointGroup, MemberVisitor};
+
+/// Finds the index of the first member the entity has.
+struct FindVariant<'a, 'w> {
+    entity: &'a EntityRef<'w>,
+    found: Option<usize>,
+}
+
+impl MemberVisitor for FindVariant<'_, '_> {
+    fn visit<M: Disjoint + Component>(&mut self) {
+        if self.found.is_none() && self.entity.contains::<M>() {
+            self.found = Some(M::INDEX);
+        }
+    }
+}
+
+/// The index of the first "variant" of `G` that the entity has, if any.
+pub(crate) fn variant_of<G: DisjointGroup>(entity: &EntityRef) -> Option<usize> {
+    let mut find = FindVariant {
+        entity,
+        found: None,
+    };
+    G::for_each_member(&mut find);
+    find.found
+}
+
+/// Removes every member except the one at `keep`.
+struct RemoveOthers<'a, 'w> {
+    entity: &'a mut EntityWorldMut<'w>,
+    keep: Option<usize>,
+}
+
+impl MemberVisitor for RemoveOthers<'_, '_> {
+    fn visit<M: Disjoint + Component>(&mut self) {
+        if Some(M::INDEX) != self.keep {
+            self.entity.remove::<M>();
+        }
+    }
+}
+
+/// Removes every "variant" of `G` from the entity, except the one at `keep`.
+pub(crate) fn remove_others<G: DisjointGroup>(entity: &mut EntityWorldMut, keep: Option<usize>) {
+    G::for_each_member(&mut RemoveOthers { entity, keep });
+}
+
+/// Whether the group `G` has a member at `index`.
+pub(crate) fn has_member<G: DisjointGroup>(index: usize) -> bool {
+    G::NAMES.get(index).is_some_and(|name| !name.is_empty())
+}
+
+/// The name of the group `G`, which is the name of its first member.
+pub(crate) fn group_name<G: DisjointGroup>() -> &'static str {
+    G::NAMES
+        .iter()
+        .copied()
+        .find(|name| !name.is_empty())
+        .unwrap_or_default()
+}
+
+/// Collects the name and (if registered) the [`ComponentId`] of every member.
+struct MemberIds<'a> {
+    components: &'a Components,
+    members: Vec<(&'static str, Option<ComponentId>)>,
+}
+
+impl MemberVisitor for MemberIds<'_> {
+    fn visit<M: Disjoint + Component>(&mut self) {
+        self.members[M::INDEX] = (M::NAME, self.components.component_id::<M>());
+    }
+}
+
+/// The name and (if registered) the [`ComponentId`] of every "variant" of `G`, in order,
+/// or `("", None)` for an index without a "variant".
+pub(crate) fn member_ids<G: DisjointGroup>(
+    components: &Components,
+) -> Vec<(&'static str, Option<ComponentId>)> {
+    let mut ids = MemberIds {
+        components,
+        members: vec![("", None); G::COUNT],
+    };
+    G::for_each_member(&mut ids);
+    ids.members
+}
+
+/// Registers every member, collecting its [`ComponentId`].
+struct InitMembers<'a> {
+    world: &'a mut World,
+    ids: Vec<Option<ComponentId>>,
+}
+
+impl MemberVisitor for InitMembers<'_> {
+    fn visit<M: Disjoint + Component>(&mut self) {
+        self.ids[M::INDEX] = Some(self.world.init_component::<M>());
+    }
+}
+
+/// The [`ComponentId`] of every "variant" of `G`, in order, registering them if needed,
+/// or `None` for an index without a "variant".
+pub(crate) fn init_member_ids<G: DisjointGroup>(world: &mut World) -> Vec<Option<ComponentId>> {
+    let mut init = InitMembers {
+        world,
+        ids: vec![None; G::COUNT],
+    };
+    G::for_each_member(&mut init);
+    init.ids
+}
+
+/// Collects the [`TypeId`] of every member.
+struct TypeIds(Vec<TypeId>);
+
+impl MemberVisitor for TypeIds {
+    fn visit<M: Disjoint + Component>(&mut self) {
+        self.0.push(TypeId::of::<M>());
+    }
+}
+
+/// The [`TypeId`] of every "variant" of `G`, in order.
+pub(crate) fn member_type_ids<G: DisjointGroup + ?Sized>() -> Vec<TypeId> {
+    let mut ids = TypeIds(Vec::new());
+    G::for_each_member(&mut ids);
+    ids.0
+}
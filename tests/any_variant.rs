@@ -0,0 +1,47 @@
+//! Checks that `AnyVariantOf` only matches the archetypes with a "variant", and that `DisjointQuery` uses it.
+
+use bevy::{
+    ecs::{query::QueryState, system::RunSystemOnce},
+    prelude::{Component, Entity, Name, World},
+};
+use bevy_djqf::{disjoint_groups, AnyVariantOf, DisjointQuery};
+
+#[derive(Component, Debug, Default)]
+struct Player;
+
+#[derive(Component, Debug, Default)]
+struct Enemy;
+
+disjoint_groups! {
+    struct GameObject { Player, Enemy },
+}
+
+#[test]
+fn only_matches_archetypes_with_a_variant() {
+    let mut world = World::new();
+    let player = world.spawn(Player).id();
+    let enemy = world.spawn((Enemy, Name::new("enemy"))).id();
+    world.spawn(Name::new("unrelated"));
+    world.spawn_empty();
+
+    let mut query = QueryState::<Entity, AnyVariantOf<GameObject>>::new(&mut world);
+    assert_eq!(query.matched_archetypes().count(), 2);
+
+    let mut matched = query.iter(&world).collect::<Vec<_>>();
+    matched.sort();
+    assert_eq!(matched, [player, enemy]);
+}
+
+#[test]
+fn iter_by_variant_of_a_struct_group() {
+    let mut world = World::new();
+    let player = world.spawn(Player).id();
+    let enemy = world.spawn(Enemy).id();
+    world.spawn(Name::new("unrelated"));
+
+    let mut pairs = world.run_system_once(|query: DisjointQuery<GameObject>| {
+        query.iter_by_variant().collect::<Vec<_>>()
+    });
+    pairs.sort();
+    assert_eq!(pairs, [(0, player), (1, enemy)]);
+}
@@ -0,0 +1,105 @@
+//! Checks what `add_disjoint_group` sets up for a group, with and without enforcement and reflection.
+
+use bevy::prelude::{App, Component};
+use bevy_djqf::{disjoint, DisjointAppExt, DisjointGroupConfig, OnViolation};
+
+// the `Reflect` derive names `bevy_reflect`, which is only a dependency with the `reflect` feature
+#[derive(Component, Debug, Default)]
+#[cfg_attr(feature = "reflect", derive(bevy::prelude::Reflect))]
+struct Player;
+
+#[derive(Component, Debug, Default)]
+#[cfg_attr(feature = "reflect", derive(bevy::prelude::Reflect))]
+struct Enemy;
+
+disjoint!(Player, Enemy);
+
+#[test]
+#[should_panic(expected = "has both `Player` and `Enemy`")]
+fn enforces_by_default() {
+    let mut app = App::new();
+    app.add_disjoint_group::<Player>();
+    app.world_mut().spawn((Player, Enemy));
+    app.update();
+}
+
+#[test]
+fn warns_when_configured() {
+    let mut app = App::new();
+    app.add_disjoint_group_with::<Player>(DisjointGroupConfig::new().enforce(OnViolation::Warn));
+    app.world_mut().spawn((Player, Enemy));
+    app.update();
+}
+
+#[test]
+fn without_enforcement() {
+    let mut app = App::new();
+    app.add_disjoint_group_with::<Player>(DisjointGroupConfig::new().without_enforcement());
+    app.world_mut().spawn((Player, Enemy));
+    app.update();
+}
+
+#[cfg(feature = "reflect")]
+#[test]
+fn registers_for_reflection_by_default() {
+    let mut app = App::new();
+    app.add_disjoint_group::<Player>();
+    let registry = app
+        .world()
+        .resource::<bevy::ecs::reflect::AppTypeRegistry>()
+        .read();
+    assert!(registry.contains(std::any::TypeId::of::<Player>()));
+    assert!(registry.contains(std::any::TypeId::of::<Enemy>()));
+}
+
+#[cfg(feature = "reflect")]
+#[test]
+fn without_reflection() {
+    let mut app = App::new();
+    app.add_disjoint_group_with::<Player>(DisjointGroupConfig::new().without_reflection());
+    let registry = app
+        .world()
+        .resource::<bevy::ecs::reflect::AppTypeRegistry>()
+        .read();
+    assert!(!registry.contains(std::any::TypeId::of::<Enemy>()));
+}
+
+#[cfg(feature = "reflect")]
+#[test]
+fn skips_reflection_of_a_group_that_cant_be_reflected() {
+    #[derive(Component, bevy::prelude::Reflect, Debug, Default)]
+    struct Wall;
+
+    #[derive(Component, Debug, Default)]
+    struct Door;
+
+    disjoint!(Wall, Door);
+
+    let mut app = App::new();
+    app.add_disjoint_group::<Wall>();
+    let registry = app
+        .world()
+        .resource::<bevy::ecs::reflect::AppTypeRegistry>()
+        .read();
+    assert!(!registry.contains(std::any::TypeId::of::<Wall>()));
+}
+
+#[cfg(feature = "diagnostics")]
+#[test]
+fn adds_diagnostics() {
+    use bevy::diagnostic::DiagnosticsStore;
+    use bevy_djqf::{Disjoint, DisjointDiagnosticsPlugin};
+
+    let mut app = App::new();
+    app.add_disjoint_group_with::<Player>(DisjointGroupConfig::new().diagnostics(true));
+    app.world_mut().spawn(Player);
+    app.update();
+
+    let store = app.world().resource::<DiagnosticsStore>();
+    let count = |index| {
+        store
+            .get(&DisjointDiagnosticsPlugin::<Player>::path(index))?
+            .value()
+    };
+    assert_eq!(count(<Player as Disjoint>::INDEX), Some(1.0));
+}
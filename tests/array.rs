@@ -0,0 +1,59 @@
+//! Checks that `DisjointGroup::Array` is a concrete `[T; COUNT]` for every kind of group,
+//! and that `PerVariant` is built on it.
+
+use std::marker::PhantomData;
+
+use bevy::prelude::Component;
+use bevy_djqf::{disjoint, Disjoint, DisjointGroup, PerVariant, VariantArray};
+
+#[derive(Component, Debug, Default)]
+struct A;
+
+#[derive(Component, Debug, Default)]
+struct B;
+
+#[derive(Component, Debug, Default)]
+struct C;
+
+disjoint!(A, B, C);
+
+#[derive(Component)]
+struct Height<T: Send + Sync + 'static>(PhantomData<T>);
+
+#[derive(Component)]
+struct Width<T: Send + Sync + 'static>(PhantomData<T>);
+
+disjoint!(<T> Height<T>, Width<T> where T: Send + Sync + 'static);
+
+// generic code can create and index the array without `[T; G::COUNT]`
+fn names<G: DisjointGroup>() -> G::Array<&'static str> {
+    VariantArray::from_fn(|index| G::NAMES[index])
+}
+
+#[test]
+fn construct_and_index() {
+    let array: [&str; 3] = names::<A>();
+    assert_eq!(array, ["A", "B", "C"]);
+    assert_eq!(array[<B as Disjoint>::INDEX], "B");
+
+    let squares: <A as DisjointGroup>::Array<usize> = VariantArray::from_fn(|index| index * index);
+    assert_eq!(squares.len(), <A as DisjointGroup>::COUNT);
+    assert_eq!(squares[<C as Disjoint>::INDEX], 4);
+}
+
+#[test]
+fn generic_group() {
+    let array: [&str; 2] = names::<Height<u8>>();
+    assert_eq!(array, <Height<u8> as DisjointGroup>::NAMES);
+}
+
+#[test]
+fn per_variant_array() {
+    let mut speeds = PerVariant::<A, f32>::from_array([1.0, 2.0, 3.0]);
+    *speeds.get_mut::<B>() *= 10.0;
+    assert_eq!(*speeds.get::<B>(), 20.0);
+
+    let copy = speeds.clone();
+    assert_eq!(speeds.into_array(), [1.0, 20.0, 3.0]);
+    assert_eq!(format!("{copy:?}"), "PerVariant([1.0, 20.0, 3.0])");
+}
@@ -0,0 +1,72 @@
+//! Checks that `OnBecameNone` is only sent when an entity truly loses its last "variant",
+//! and not when it swaps "variants" in the same frame, or is despawned.
+
+use bevy::prelude::{App, Component, Entity, ResMut, Resource, Trigger};
+use bevy_djqf::{disjoint, DisjointEntityWorldMutExt, OnBecameNone, VariantChangedPlugin};
+
+#[derive(Component, Debug, Default)]
+struct A;
+
+#[derive(Component, Debug, Default)]
+struct B;
+
+disjoint!(A, B);
+
+#[derive(Resource, Default)]
+struct BecameNone(Vec<(Entity, usize)>);
+
+fn app() -> App {
+    let mut app = App::new();
+    app.add_plugins(VariantChangedPlugin::<A>::default())
+        .init_resource::<BecameNone>()
+        .observe(
+            |trigger: Trigger<OnBecameNone<A>>, mut became_none: ResMut<BecameNone>| {
+                let event = trigger.event();
+                became_none.0.push((event.entity, event.old));
+            },
+        );
+    app
+}
+
+#[test]
+fn removing_the_last_variant() {
+    let mut app = app();
+    let entity = app.world_mut().spawn(B).id();
+    app.update();
+    assert!(app.world().resource::<BecameNone>().0.is_empty());
+
+    app.world_mut().entity_mut(entity).remove::<B>();
+    app.update();
+    assert_eq!(app.world().resource::<BecameNone>().0, [(entity, 1)]);
+
+    // already none
+    app.update();
+    assert_eq!(app.world().resource::<BecameNone>().0.len(), 1);
+}
+
+#[test]
+fn swapping_variants() {
+    let mut app = app();
+    let entity = app.world_mut().spawn(A).id();
+    app.update();
+
+    // removes `A`, then adds `B`, in the same frame
+    app.world_mut().entity_mut(entity).set_variant::<B>();
+    app.update();
+    app.world_mut().entity_mut(entity).remove::<B>().insert(A);
+    app.update();
+
+    assert!(app.world().resource::<BecameNone>().0.is_empty());
+}
+
+#[test]
+fn despawning() {
+    let mut app = app();
+    let entity = app.world_mut().spawn(A).id();
+    app.update();
+
+    app.world_mut().despawn(entity);
+    app.update();
+
+    assert!(app.world().resource::<BecameNone>().0.is_empty());
+}
@@ -0,0 +1,84 @@
+//! Checks that the macros of `bevy_djqf` work in a crate that depends on `bevy`, but not on `bevy_ecs` directly.
+//!
+//! # Example
+//! ```
+//! use bevy::prelude::World;
+//! use bevy_only::{count, Allies, Enemy, Player};
+//!
+//! let mut world = World::new();
+//! world.spawn(Player);
+//! world.spawn(Enemy);
+//! world.spawn((Player, Enemy));
+//!
+//! assert_eq!(count::<<Player as bevy_djqf::Disjoint>::Only>(&mut world), 1);
+//! assert_eq!(count::<Allies>(&mut world), 1);
+//! ```
+
+use bevy::{
+    ecs::query::QueryFilter,
+    prelude::{Component, Query, Transform, World},
+};
+use bevy_djqf::{disjoint, disjoint_subset, make_disjoint_markers, Disjoint};
+
+#[derive(Component, Debug, Default)]
+pub struct Player;
+
+#[derive(Component, Debug, Default)]
+pub struct Ally;
+
+#[derive(Component, Debug, Default)]
+pub struct Enemy;
+
+disjoint!(
+    Player,
+    Ally,
+    #[default]
+    Enemy
+);
+
+pub type Allies = disjoint_subset!(Player, Ally);
+
+#[derive(Component, Debug, Default)]
+pub struct Boss;
+
+#[derive(Component, Debug, Default)]
+pub struct Minion;
+
+#[derive(Component, Debug, Default)]
+pub struct Critter;
+
+disjoint!(
+    #[cfg(feature = "special")]
+    Boss,
+    Minion,
+    Critter
+);
+
+// `Boss` is only a member with the `special` feature
+const _: () = assert!(<Minion as Disjoint>::COUNT == if cfg!(feature = "special") { 3 } else { 2 });
+
+macro_rules! type_template {
+    ($vis:vis $Name:ident) => {
+        #[derive(Component, Debug, Default)]
+        $vis struct $Name;
+    };
+}
+
+make_disjoint_markers!(pub type_template for Red, Blue; aliases);
+
+#[derive(bevy_djqf::Disjoint)]
+pub enum Shape {
+    Circle,
+    Square,
+}
+
+pub fn only_players(_query: Query<&mut Transform, <Player as Disjoint>::Only>) {}
+
+pub fn except_red(_query: Query<&mut Transform, RedOther>) {}
+
+pub fn any_shape(_query: Query<&mut Transform, ShapeAny>) {}
+
+/// The number of entities matching the filter `F`.
+pub fn count<F: QueryFilter>(world: &mut World) -> usize {
+    world.query_filtered::<(), F>().iter(world).count()
+}
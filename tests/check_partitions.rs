@@ -0,0 +1,71 @@
+//! Checks that `assert_group_partitions!` passes for groups declared in a test suite,
+//! and that it can generate the test itself.
+
+use bevy::prelude::Component;
+use bevy_djqf::{assert_group_partitions, disjoint, disjoint_groups};
+
+#[derive(Component, Debug, Default)]
+struct Player;
+
+#[derive(Component, Debug, Default)]
+struct Ally;
+
+#[derive(Component, Debug, Default)]
+struct Enemy;
+
+disjoint!(Player, Ally, Enemy);
+
+#[derive(Component, Debug, Default)]
+struct Red;
+
+#[derive(Component, Debug, Default)]
+struct Blue;
+
+disjoint_groups! {
+    struct Team { Red, Blue },
+}
+
+assert_group_partitions!(generated_test: Team);
+
+#[test]
+fn in_a_test() {
+    assert_group_partitions!(Player);
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn derived_group() {
+    #[derive(bevy_djqf::Disjoint)]
+    #[allow(dead_code)]
+    enum Shape {
+        Circle,
+        Square,
+        Triangle,
+        Hexagon,
+    }
+
+    assert_group_partitions!(Circle);
+}
+
+#[test]
+#[should_panic(expected = "would spawn 2^21 entities")]
+fn too_large() {
+    mod large {
+        use bevy::prelude::Component;
+
+        macro_rules! marker {
+            ($($name:ident),+) => {
+                $(
+                    #[derive(Component, Debug, Default)]
+                    pub struct $name;
+                )+
+
+                bevy_djqf::disjoint!($($name),+);
+            };
+        }
+
+        marker!(A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P, Q, R, S, T, U);
+    }
+
+    assert_group_partitions!(large::A);
+}
@@ -0,0 +1,34 @@
+//! Checks that enums with `#[derive(Disjoint)]` display as the names of their variants.
+
+use bevy_djqf::{Disjoint, DisjointEnum};
+
+#[derive(bevy_djqf::Disjoint, Clone, Copy, Debug, PartialEq)]
+enum GameObject {
+    Player,
+    Enemy,
+    Wall,
+}
+
+#[test]
+fn to_string() {
+    assert_eq!(GameObject::Player.to_string(), "Player");
+    assert_eq!(GameObject::Enemy.to_string(), "Enemy");
+    assert_eq!(GameObject::Wall.to_string(), "Wall");
+}
+
+#[test]
+fn same_as_names_and_debug() {
+    for value in GameObject::iter() {
+        assert_eq!(
+            value.to_string(),
+            <Player as Disjoint>::NAMES[value.index()]
+        );
+        assert_eq!(value.to_string(), format!("{value:?}"));
+    }
+}
+
+#[test]
+fn padding() {
+    assert_eq!(format!("[{:>6}]", GameObject::Wall), "[  Wall]");
+    assert_eq!(format!("[{:<6}]", GameObject::Enemy), "[Enemy ]");
+}
@@ -0,0 +1,127 @@
+//! Checks that the filters built by `DynamicDisjoint` match the same entities as the filters generated by `disjoint!`,
+//! by spawning one entity for every combination of members.
+
+use bevy::{
+    ecs::query::{QueryBuilder, QueryFilter},
+    prelude::{Component, Entity, World},
+};
+use bevy_djqf::{disjoint, Disjoint, DisjointGroup, DynamicDisjoint};
+
+#[derive(Component)]
+struct A;
+
+#[derive(Component)]
+struct B;
+
+#[derive(Component)]
+struct C;
+
+#[derive(Component)]
+struct D;
+
+disjoint!(A, B, C, D);
+
+fn matching<F: QueryFilter>(world: &mut World) -> Vec<Entity> {
+    let mut entities = world
+        .query_filtered::<Entity, F>()
+        .iter(world)
+        .collect::<Vec<_>>();
+    entities.sort();
+    entities
+}
+
+fn matching_dynamic(world: &mut World, filter: impl Fn(&mut QueryBuilder<Entity>)) -> Vec<Entity> {
+    let mut builder = QueryBuilder::<Entity>::new(world);
+    filter(&mut builder);
+    let mut query = builder.build();
+    let mut entities = query.iter(world).collect::<Vec<_>>();
+    entities.sort();
+    entities
+}
+
+fn check_member<V: Disjoint + Component>(world: &mut World, group: &DynamicDisjoint) {
+    let id = world.component_id::<V>().unwrap();
+    assert_eq!(group.index(id), Some(V::INDEX), "{} index", V::NAME);
+    assert_eq!(
+        matching_dynamic(world, |builder| group.only(builder, id)),
+        matching::<V::Only>(world),
+        "{} Only",
+        V::NAME
+    );
+    assert_eq!(
+        matching_dynamic(world, |builder| group.other(builder, id)),
+        matching::<V::Other>(world),
+        "{} Other",
+        V::NAME
+    );
+}
+
+#[test]
+fn same_as_static_filters() {
+    let mut world = World::new();
+    for mask in 0..1u32 << <A as DisjointGroup>::COUNT {
+        let mut entity = world.spawn_empty();
+        if mask & 1 != 0 {
+            entity.insert(A);
+        }
+        if mask & 2 != 0 {
+            entity.insert(B);
+        }
+        if mask & 4 != 0 {
+            entity.insert(C);
+        }
+        if mask & 8 != 0 {
+            entity.insert(D);
+        }
+    }
+
+    let group = DynamicDisjoint::from_group::<A>(&mut world);
+    assert_eq!(group.len(), 4);
+
+    check_member::<A>(&mut world, &group);
+    check_member::<B>(&mut world, &group);
+    check_member::<C>(&mut world, &group);
+    check_member::<D>(&mut world, &group);
+
+    assert_eq!(
+        matching_dynamic(&mut world, |builder| group.any(builder)),
+        matching::<<A as Disjoint>::Any>(&mut world),
+        "Any"
+    );
+    assert_eq!(
+        matching_dynamic(&mut world, |builder| group.none(builder)),
+        matching::<<A as Disjoint>::None>(&mut world),
+        "None"
+    );
+}
+
+#[test]
+fn single_member_has_no_other() {
+    let mut world = World::new();
+    let a = world.init_component::<A>();
+    world.spawn(A);
+    world.spawn(B);
+    world.spawn_empty();
+
+    let group = DynamicDisjoint::new([a]);
+    assert!(matching_dynamic(&mut world, |builder| group.other(builder, a)).is_empty());
+    assert_eq!(
+        matching_dynamic(&mut world, |builder| group.only(builder, a)).len(),
+        1
+    );
+}
+
+#[test]
+fn empty_group_matches_nothing() {
+    let mut world = World::new();
+    world.spawn(A);
+    world.spawn_empty();
+
+    let group = DynamicDisjoint::default();
+    assert!(matching_dynamic(&mut world, |builder| group.any(builder)).is_empty());
+    assert!(matching_dynamic(&mut world, |builder| group.any_of(builder, [0])).is_empty());
+    assert_eq!(
+        matching_dynamic(&mut world, |builder| group.none(builder)).len(),
+        2
+    );
+}
@@ -0,0 +1,40 @@
+//! Checks that `DisjointEnforcePlugin` names the "variant" that was added to an entity that already had another one.
+
+use bevy::prelude::{App, Commands, Component, Entity, Query, Update, With};
+use bevy_djqf::{disjoint, DisjointEnforcePlugin};
+
+#[derive(Component, Debug, Default)]
+struct Player;
+
+#[derive(Component, Debug, Default)]
+struct Enemy;
+
+disjoint!(Player, Enemy);
+
+// wrongly adds a second "variant", instead of using `set_variant`
+fn make_enemies(mut commands: Commands, players: Query<Entity, With<Player>>) {
+    for entity in &players {
+        commands.entity(entity).insert(Enemy);
+    }
+}
+
+#[test]
+#[should_panic(expected = "gained `Enemy` while already having `Player`")]
+fn names_the_added_variant() {
+    let mut app = App::new();
+    app.add_plugins(DisjointEnforcePlugin::<Player>::default());
+    app.world_mut().spawn(Player);
+    app.update();
+
+    app.add_systems(Update, make_enemies);
+    app.update();
+}
+
+#[test]
+#[should_panic(expected = "has both `Player` and `Enemy`")]
+fn spawned_with_both() {
+    let mut app = App::new();
+    app.add_plugins(DisjointEnforcePlugin::<Player>::default());
+    app.world_mut().spawn((Player, Enemy));
+    app.update();
+}
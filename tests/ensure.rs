@@ -0,0 +1,74 @@
+//! Checks that `ensure_variant` only inserts a "variant" on entities with none, and keeps an existing one.
+
+use bevy::{
+    ecs::world::CommandQueue,
+    prelude::{Commands, Component, Entity, World},
+};
+use bevy_djqf::{
+    disjoint, DefaultVariant, DisjointEntityCommandsExt, DisjointEntityWorldMutExt, VariantOf,
+};
+
+#[derive(Component, Debug, Default, PartialEq)]
+struct Idle(u32);
+
+#[derive(Component, Debug, Default)]
+struct Walking;
+
+#[derive(Component, Debug, Default)]
+struct Running;
+
+disjoint!(
+    #[default]
+    Idle,
+    Walking,
+    Running
+);
+
+fn ensure_idle(world: &mut World, entity: Entity) {
+    let mut queue = CommandQueue::default();
+    Commands::new(&mut queue, world)
+        .entity(entity)
+        .ensure_variant::<<Idle as DefaultVariant>::Default>();
+    queue.apply(world);
+}
+
+fn variant(world: &mut World, entity: Entity) -> Option<usize> {
+    world.query::<VariantOf<Idle>>().get(world, entity).unwrap()
+}
+
+#[test]
+fn no_variant() {
+    let mut world = World::new();
+    let entity = world.spawn_empty().id();
+
+    ensure_idle(&mut world, entity);
+    assert_eq!(variant(&mut world, entity), Some(0));
+    assert_eq!(world.get::<Idle>(entity), Some(&Idle(0)));
+}
+
+#[test]
+fn has_variant() {
+    let mut world = World::new();
+    let walking = world.spawn(Walking).id();
+    let idle = world.spawn(Idle(3)).id();
+
+    ensure_idle(&mut world, walking);
+    assert_eq!(variant(&mut world, walking), Some(1));
+    assert!(!world.entity(walking).contains::<Idle>());
+
+    // the existing value isn't replaced with the default
+    ensure_idle(&mut world, idle);
+    assert_eq!(world.get::<Idle>(idle), Some(&Idle(3)));
+}
+
+#[test]
+fn immediately() {
+    let mut world = World::new();
+    let mut entity = world.spawn_empty();
+    entity.ensure_variant::<Running>();
+    assert!(entity.contains::<Running>());
+
+    entity.ensure_variant::<Walking>();
+    assert!(entity.contains::<Running>());
+    assert!(!entity.contains::<Walking>());
+}
@@ -0,0 +1,42 @@
+//! Checks that `ExclusiveVariantQuery` works with a struct group from `disjoint_groups!`, which only implements `DisjointGroup`.
+
+use bevy::{
+    ecs::system::RunSystemOnce,
+    prelude::{Component, Name, World},
+};
+use bevy_djqf::{disjoint_groups, ExclusiveVariantQuery};
+
+#[derive(Component, Debug, Default)]
+struct Player;
+
+#[derive(Component, Debug, Default)]
+struct Enemy;
+
+disjoint_groups! {
+    struct GameObject { Player, Enemy },
+}
+
+#[test]
+fn iterates_any_variant_of_a_struct_group() {
+    let mut world = World::new();
+    world.spawn((Player, Name::new("player")));
+    world.spawn((Enemy, Name::new("enemy")));
+    world.spawn(Name::new("unrelated"));
+
+    let mut names = world.run_system_once(|query: ExclusiveVariantQuery<GameObject, &Name>| {
+        query
+            .iter()
+            .map(|name| name.as_str().to_owned())
+            .collect::<Vec<_>>()
+    });
+    names.sort();
+    assert_eq!(names, ["enemy", "player"]);
+}
+
+#[test]
+#[should_panic(expected = "has both `Player` and `Enemy`")]
+fn panics_on_both_variants_of_a_struct_group() {
+    let mut world = World::new();
+    world.spawn((Player, Enemy));
+    world.run_system_once(|query: ExclusiveVariantQuery<GameObject>| query.iter().count());
+}
@@ -0,0 +1,134 @@
+//! Checks that the indices given to `disjoint!` are used for `INDEX`, and everything that is ordered by it,
+//! regardless of the order of the types.
+
+use bevy::{
+    ecs::{system::RunSystemOnce, world::CommandQueue},
+    prelude::{Commands, Component, World},
+};
+use bevy_djqf::{
+    check_partitions, disjoint, variant_histogram, Disjoint, DisjointEntityCommandsExt,
+    DisjointGroup, DisjointQuery, DynamicDisjoint, OutOfRange, PerVariant, VariantOf,
+};
+
+#[derive(Component, Debug, Default)]
+struct Player;
+
+#[derive(Component, Debug, Default)]
+struct Enemy;
+
+#[derive(Component, Debug, Default)]
+struct Wall;
+
+// `Wall` was added after `Enemy`, but listed before it
+disjoint!(Player = 0, Wall = 2, Enemy = 1);
+
+#[test]
+fn indices() {
+    assert_eq!(<Player as Disjoint>::INDEX, 0);
+    assert_eq!(<Enemy as Disjoint>::INDEX, 1);
+    assert_eq!(<Wall as Disjoint>::INDEX, 2);
+    assert_eq!(<Player as DisjointGroup>::COUNT, 3);
+    assert_eq!(
+        <Player as DisjointGroup>::NAMES,
+        ["Player", "Enemy", "Wall"]
+    );
+    assert_eq!(<Wall as Disjoint>::NAMES[<Wall as Disjoint>::INDEX], "Wall");
+}
+
+#[test]
+fn members_in_index_order() {
+    let mut world = World::new();
+    let enemy = world.spawn(Enemy).id();
+    let wall = world.spawn(Wall).id();
+
+    let mut variant = world.query::<VariantOf<Player>>();
+    assert_eq!(variant.get(&world, enemy).unwrap(), Some(1));
+    assert_eq!(variant.get(&world, wall).unwrap(), Some(2));
+
+    let variants = world.run_system_once(move |query: DisjointQuery<Player>| {
+        [query.variant(enemy), query.variant(wall)]
+    });
+    assert_eq!(variants, [Some(1), Some(2)]);
+
+    let group = DynamicDisjoint::from_group::<Player>(&mut world);
+    let wall_id = world.component_id::<Wall>().unwrap();
+    assert_eq!(group.index(wall_id), Some(2));
+
+    let speeds = PerVariant::<Player, f32>::from_array([1.0, 2.0, 3.0]);
+    assert_eq!(*speeds.get::<Wall>(), 3.0);
+}
+
+#[test]
+fn filters() {
+    let mut world = World::new();
+    world.spawn(Player);
+    world.spawn(Wall);
+    world.spawn((Enemy, Wall));
+
+    let mut walls = world.query_filtered::<(), <Wall as Disjoint>::Only>();
+    assert_eq!(walls.iter(&world).count(), 1);
+    let mut not_walls = world.query_filtered::<(), <Wall as Disjoint>::Other>();
+    assert_eq!(not_walls.iter(&world).count(), 1);
+}
+
+#[derive(Component, Debug, Default)]
+struct Sword;
+
+#[derive(Component, Debug, Default)]
+struct Bow;
+
+// the "variant" at index 1 was retired, and its index isn't reused
+disjoint!(Sword = 0, Bow = 2);
+
+#[test]
+fn gaps() {
+    assert_eq!(<Sword as DisjointGroup>::COUNT, 3);
+    assert_eq!(<Bow as Disjoint>::COUNT, 3);
+    assert_eq!(<Sword as DisjointGroup>::NAMES, ["Sword", "", "Bow"]);
+    assert_eq!(<Bow as Disjoint>::NAMES[<Bow as Disjoint>::INDEX], "Bow");
+
+    let mut world = World::new();
+    let entity = world.spawn(Sword).id();
+
+    let mut queue = CommandQueue::default();
+    let mut commands = Commands::new(&mut queue, &world);
+    assert_eq!(
+        commands
+            .entity(entity)
+            .set_variant_by_index::<Sword>(1)
+            .err(),
+        Some(OutOfRange { index: 1, count: 3 }),
+    );
+    assert!(commands
+        .entity(entity)
+        .set_variant_by_name::<Sword>("")
+        .is_err());
+    assert!(commands
+        .entity(entity)
+        .set_variant_by_index::<Sword>(2)
+        .is_ok());
+    queue.apply(&mut world);
+    assert!(!world.entity(entity).contains::<Sword>());
+    assert!(world.entity(entity).contains::<Bow>());
+
+    let mut variant = world.query::<VariantOf<Sword>>();
+    assert_eq!(variant.get(&world, entity).unwrap(), Some(2));
+
+    let group = DynamicDisjoint::from_group::<Sword>(&mut world);
+    let bow_id = world.component_id::<Bow>().unwrap();
+    assert_eq!(group.len(), 3);
+    assert_eq!(group.index(bow_id), Some(2));
+
+    assert_eq!(
+        variant_histogram::<Sword>(&world),
+        [("Sword", 0), ("Bow", 1)]
+    );
+
+    let damage = PerVariant::<Sword, u32>::from_array([5, 0, 3]);
+    assert_eq!(*damage.get::<Bow>(), 3);
+}
+
+#[test]
+fn gaps_partition() {
+    check_partitions::<Sword>();
+}
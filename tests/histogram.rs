@@ -0,0 +1,43 @@
+//! Checks that `variant_histogram` counts the entities with only each "variant",
+//! and that `log_variant_histogram` runs as a system.
+
+use bevy::{
+    ecs::system::RunSystemOnce,
+    prelude::{Component, World},
+};
+use bevy_djqf::{disjoint, log_variant_histogram, variant_histogram};
+
+#[derive(Component, Debug, Default)]
+struct Worker;
+
+#[derive(Component, Debug, Default)]
+struct Soldier;
+
+#[derive(Component, Debug, Default)]
+struct Scout;
+
+disjoint!(Worker, Soldier, Scout);
+
+#[test]
+fn counts_only() {
+    let mut world = World::new();
+    assert_eq!(
+        variant_histogram::<Worker>(&world),
+        [("Worker", 0), ("Soldier", 0), ("Scout", 0)]
+    );
+
+    for _ in 0..3 {
+        world.spawn(Worker);
+    }
+    world.spawn(Soldier);
+    // has more than one "variant", so it isn't counted
+    world.spawn((Worker, Scout));
+    world.spawn_empty();
+
+    assert_eq!(
+        variant_histogram::<Worker>(&world),
+        [("Worker", 3), ("Soldier", 1), ("Scout", 0)]
+    );
+
+    world.run_system_once(log_variant_histogram::<Worker>);
+}
@@ -0,0 +1,204 @@
+//! Checks that the macros still expand when the "variants" have the same names as the items they use,
+//! e.g. a marker named `Only`, `Disjoint` or `V`, or a `stringify!` macro that shadows the built-in one.
+
+use bevy::prelude::{Entity, World};
+
+fn matching<F: bevy::ecs::query::QueryFilter>(world: &mut World) -> Vec<Entity> {
+    let mut entities = world
+        .query_filtered::<Entity, F>()
+        .iter(world)
+        .collect::<Vec<_>>();
+    entities.sort();
+    entities
+}
+
+mod declared {
+    use bevy::prelude::{Component, World};
+    use bevy_djqf::{disjoint, disjoint_groups, for_each_variant};
+
+    use super::matching;
+
+    #[allow(unused_macros)]
+    macro_rules! stringify {
+        ($($tokens:tt)*) => {
+            "shadowed"
+        };
+    }
+
+    #[derive(Component)]
+    struct Only;
+
+    #[derive(Component)]
+    struct Other;
+
+    #[derive(Component)]
+    struct Any;
+
+    #[derive(Component)]
+    struct Not;
+
+    #[derive(Component)]
+    struct None;
+
+    #[derive(Component)]
+    struct Disjoint;
+
+    #[derive(Component)]
+    struct DisjointGroup;
+
+    #[derive(Component)]
+    struct Group;
+
+    #[derive(Component)]
+    struct V;
+
+    #[derive(Component)]
+    struct ForEachVariant;
+
+    #[derive(Component)]
+    struct With;
+
+    #[derive(Component)]
+    struct Without;
+
+    #[derive(Component)]
+    struct Or;
+
+    disjoint!(
+        Only,
+        Other,
+        Any,
+        Not,
+        None,
+        Disjoint,
+        DisjointGroup,
+        Group,
+        V,
+        ForEachVariant
+    );
+
+    #[derive(Component)]
+    struct Debug;
+
+    #[derive(Component)]
+    struct Default;
+
+    disjoint_groups! {
+        struct Filters { With, Without, Or, Debug, Default },
+    }
+
+    #[test]
+    fn names() {
+        assert_eq!(
+            <Only as bevy_djqf::DisjointGroup>::NAMES,
+            [
+                "Only",
+                "Other",
+                "Any",
+                "Not",
+                "None",
+                "Disjoint",
+                "DisjointGroup",
+                "Group",
+                "V",
+                "ForEachVariant"
+            ]
+        );
+        assert_eq!(<V as bevy_djqf::Disjoint>::NAME, "V");
+        assert_eq!(<V as bevy_djqf::Disjoint>::INDEX, 8);
+        assert_eq!(<Filters as bevy_djqf::DisjointGroup>::NAMES[2], "Or");
+    }
+
+    #[test]
+    fn for_each_member() {
+        let mut names = Vec::new();
+        let names_ref = &mut names;
+        for_each_variant!(
+            <ForEachVariant as bevy_djqf::Disjoint>::Group,
+            |M, names_ref: &mut Vec<&'static str>| names_ref.push(<M as bevy_djqf::Disjoint>::NAME)
+        );
+        assert_eq!(names, <Only as bevy_djqf::DisjointGroup>::NAMES);
+    }
+
+    #[test]
+    fn filters() {
+        let mut world = World::new();
+        let only = world.spawn(Only).id();
+        let v = world.spawn(V).id();
+        world.spawn((Only, V));
+        let none = world.spawn_empty().id();
+        let with = world.spawn(With).id();
+
+        assert_eq!(
+            matching::<<Only as bevy_djqf::Disjoint>::Only>(&mut world),
+            [only]
+        );
+        assert_eq!(
+            matching::<<Only as bevy_djqf::Disjoint>::Other>(&mut world),
+            [v]
+        );
+        assert_eq!(
+            matching::<<Only as bevy_djqf::Disjoint>::None>(&mut world),
+            [none, with]
+        );
+        assert_eq!(
+            matching::<<With as bevy_djqf::Disjoint>::Only>(&mut world),
+            [with]
+        );
+    }
+}
+
+#[cfg(feature = "derive")]
+mod derived {
+    use bevy::{
+        ecs::query::QueryBuilder,
+        prelude::{Entity, World},
+    };
+    use bevy_djqf::DisjointEnum;
+
+    use super::matching;
+
+    #[derive(bevy_djqf::Disjoint, Clone, Copy, Debug, PartialEq)]
+    enum Adversarial {
+        Only,
+        Other,
+        Any,
+        None,
+        Disjoint,
+        V,
+        D,
+        F,
+        I,
+    }
+
+    #[test]
+    fn enum_round_trip() {
+        for value in Adversarial::ALL {
+            assert_eq!(Adversarial::from_index(value.index()), Some(value));
+        }
+        assert_eq!(Adversarial::try_from(5u8), Ok(Adversarial::V));
+        assert_eq!(<D as bevy_djqf::Disjoint>::NAME, "D");
+    }
+
+    #[test]
+    fn mask_filter() {
+        let mut world = World::new();
+        world.spawn(Only);
+        let d = world.spawn(D).id();
+        let f = world.spawn(F).id();
+        world.spawn(I);
+
+        let mask = AdversarialMask::from_iter([Adversarial::D, Adversarial::F]);
+        let mut builder = QueryBuilder::<Entity>::new(&mut world);
+        mask.filter(&mut builder);
+        let mut query = builder.build();
+        let mut entities = query.iter(&world).collect::<Vec<_>>();
+        entities.sort();
+        assert_eq!(entities, [d, f]);
+
+        assert_eq!(
+            matching::<<D as bevy_djqf::Disjoint>::Only>(&mut world),
+            [d]
+        );
+    }
+}
@@ -0,0 +1,55 @@
+//! Checks the fields of `DisjointInfo` for a sample group, from the group and from the derive.
+
+use std::any::TypeId;
+
+use bevy::prelude::Component;
+use bevy_djqf::{disjoint, DisjointGroup, DisjointInfo};
+
+#[derive(Component, Debug, Default)]
+struct Player;
+
+#[derive(Component, Debug, Default)]
+struct Enemy;
+
+#[derive(Component, Debug, Default)]
+struct Wall;
+
+disjoint!(Player, Enemy, Wall);
+
+const GAME_OBJECT_INFO: DisjointInfo = <Player as DisjointGroup>::INFO;
+
+#[test]
+fn group_info() {
+    assert_eq!(GAME_OBJECT_INFO.count, 3);
+    assert_eq!(GAME_OBJECT_INFO.names, ["Player", "Enemy", "Wall"]);
+    assert_eq!(
+        GAME_OBJECT_INFO.type_ids(),
+        [
+            TypeId::of::<Player>(),
+            TypeId::of::<Enemy>(),
+            TypeId::of::<Wall>()
+        ]
+    );
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn derived_info() {
+    mod derived {
+        #[derive(bevy_djqf::Disjoint)]
+        pub enum Shape {
+            Circle,
+            Square,
+        }
+    }
+
+    assert_eq!(derived::Shape::INFO.count, 2);
+    assert_eq!(derived::Shape::INFO.names, ["Circle", "Square"]);
+    assert_eq!(
+        derived::Shape::INFO.type_ids(),
+        [
+            TypeId::of::<derived::Circle>(),
+            TypeId::of::<derived::Square>()
+        ]
+    );
+}
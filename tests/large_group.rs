@@ -0,0 +1,81 @@
+//! Checks that `disjoint!` expands for groups far larger than the tuple size limit of query filters,
+//! without reaching the default recursion limit.
+
+use bevy::ecs::world::CommandQueue;
+use bevy::prelude::{Commands, Component, Entity, World};
+use bevy_djqf::{
+    disjoint, Disjoint, DisjointCommandsExt, DisjointEntityCommandsExt, DisjointGroup,
+};
+
+macro_rules! components {
+    ($( $name:ident ),+) => {
+        $(
+            #[derive(Component, Default)]
+            struct $name;
+        )+
+
+        disjoint!($( $name ),+);
+    };
+}
+
+components!(
+    V0, V1, V2, V3, V4, V5, V6, V7, V8, V9, V10, V11, V12, V13, V14, V15, V16, V17, V18, V19, V20,
+    V21, V22, V23, V24, V25, V26, V27, V28, V29, V30, V31, V32, V33, V34, V35, V36, V37, V38, V39,
+    V40, V41, V42, V43, V44, V45, V46, V47, V48, V49, V50, V51, V52, V53, V54, V55, V56, V57, V58,
+    V59, V60, V61, V62, V63, V64, V65, V66, V67, V68, V69, V70, V71, V72, V73, V74, V75, V76, V77,
+    V78, V79, V80, V81, V82, V83, V84, V85, V86, V87, V88, V89, V90, V91, V92, V93, V94, V95, V96,
+    V97, V98, V99, V100, V101, V102, V103, V104, V105, V106, V107, V108, V109, V110, V111, V112,
+    V113, V114, V115, V116, V117, V118, V119, V120, V121, V122, V123, V124, V125, V126, V127
+);
+
+#[test]
+fn group_of_128() {
+    assert_eq!(<V0 as DisjointGroup>::COUNT, 128);
+    assert_eq!(<V127 as Disjoint>::INDEX, 127);
+    assert_eq!(<V64 as Disjoint>::NAME, "V64");
+
+    let mut world = World::new();
+    let v0 = world.spawn(V0).id();
+    let v64 = world.spawn(V64).id();
+    let v127 = world.spawn(V127).id();
+    let both = world.spawn((V0, V127)).id();
+    let none = world.spawn_empty().id();
+
+    let mut only = world.query_filtered::<Entity, <V127 as Disjoint>::Only>();
+    assert_eq!(only.iter(&world).collect::<Vec<_>>(), [v127]);
+
+    let mut other = world.query_filtered::<Entity, <V127 as Disjoint>::Other>();
+    let mut other = other.iter(&world).collect::<Vec<_>>();
+    other.sort();
+    assert_eq!(other, [v0, v64]);
+
+    let mut any = world.query_filtered::<Entity, <V64 as Disjoint>::Any>();
+    assert_eq!(any.iter(&world).count(), 4);
+    assert!(any.get(&world, both).is_ok());
+
+    let mut nothing = world.query_filtered::<Entity, <V64 as Disjoint>::None>();
+    assert_eq!(nothing.iter(&world).collect::<Vec<_>>(), [none]);
+}
+
+#[test]
+fn insert_by_index_beyond_16() {
+    let mut world = World::new();
+    let entity = world.spawn(V0).id();
+
+    let mut queue = CommandQueue::default();
+    let mut commands = Commands::new(&mut queue, &world);
+    assert!(commands
+        .entity(entity)
+        .set_variant_by_index::<V0>(100)
+        .is_ok());
+    let spawned = commands
+        .spawn_variants_from_fn::<V0>(3, |i| 125 + i)
+        .unwrap();
+    assert!(commands.spawn_variants_from_fn::<V0>(1, |_| 128).is_err());
+    queue.apply(&mut world);
+
+    assert!(!world.entity(entity).contains::<V0>());
+    assert!(world.entity(entity).contains::<V100>());
+    assert!(world.entity(spawned[0]).contains::<V125>());
+    assert!(world.entity(spawned[2]).contains::<V127>());
+}
@@ -0,0 +1,67 @@
+//! Checks that the `{Enum}Mask` generated by `#[derive(Disjoint)]` filters a `QueryBuilder` to the entities
+//! with any of its "variants".
+
+use bevy::{
+    ecs::query::QueryBuilder,
+    prelude::{Entity, World},
+};
+use bevy_djqf::DisjointEnum;
+
+#[derive(bevy_djqf::Disjoint, Clone, Copy, Debug, PartialEq)]
+enum GameObject {
+    Player,
+    Ally,
+    Enemy,
+    Wall,
+}
+
+fn matching(world: &mut World, mask: GameObjectMask) -> Vec<Entity> {
+    let mut builder = QueryBuilder::<Entity>::new(world);
+    mask.filter(&mut builder);
+    let mut query = builder.build();
+    let mut entities = query.iter(world).collect::<Vec<_>>();
+    entities.sort();
+    entities
+}
+
+#[test]
+fn build_mask() {
+    let mut mask = GameObjectMask::EMPTY;
+    assert!(mask.is_empty());
+    mask.insert(GameObject::Enemy.index());
+    mask.insert(GameObject::Wall.index());
+    mask.insert(GameObject::Wall.index());
+    assert_eq!(mask.len(), 2);
+    assert!(mask.contains(2));
+    assert!(!mask.contains(0));
+    assert!(!mask.contains(GameObject::ALL.len()));
+
+    mask.remove(GameObject::Enemy.index());
+    assert_eq!(mask, GameObjectMask::from(GameObject::Wall));
+    assert_eq!(GameObjectMask::ALL, GameObject::iter().collect());
+    assert_eq!(GameObjectMask::ALL.len(), 4);
+}
+
+#[test]
+#[should_panic(expected = "there is no value at index 4")]
+fn insert_out_of_range() {
+    GameObjectMask::default().insert(4);
+}
+
+#[test]
+fn query_subset() {
+    let mut world = World::new();
+    let player = world.spawn(Player).id();
+    let ally = world.spawn(Ally).id();
+    let enemy = world.spawn(Enemy).id();
+    let wall = world.spawn(Wall).id();
+    world.spawn_empty();
+
+    let allies = GameObjectMask::from_iter([GameObject::Player, GameObject::Ally]);
+    assert_eq!(matching(&mut world, allies), [player, ally]);
+    assert_eq!(
+        matching(&mut world, GameObjectMask::ALL),
+        [player, ally, enemy, wall]
+    );
+    assert!(matching(&mut world, GameObjectMask::EMPTY).is_empty());
+}
@@ -0,0 +1,62 @@
+//! Checks that the metadata generated by `disjoint_with_meta!` is in the same order as `INDEX`,
+//! for any type of metadata.
+
+use bevy::prelude::{Color, Component};
+use bevy_djqf::{disjoint_with_meta, Disjoint, DisjointGroup};
+
+#[derive(Component)]
+struct Player;
+
+#[derive(Component)]
+struct Ally;
+
+#[derive(Component)]
+struct Enemy;
+
+disjoint_with_meta! {
+    const COLOR: Color;
+    Player => Color::srgb(0.0, 0.0, 1.0),
+    Ally => Color::srgb(0.0, 1.0, 0.0),
+    Enemy => Color::srgb(1.0, 0.0, 0.0),
+}
+
+#[derive(Debug, PartialEq)]
+struct Label {
+    name: &'static str,
+    hostile: bool,
+}
+
+#[derive(Component)]
+struct Wall;
+
+#[derive(Component)]
+struct Floor;
+
+disjoint_with_meta! {
+    const LABEL: Label;
+    Wall => Label { name: "wall", hostile: false },
+    Floor => Label { name: "floor", hostile: false },
+}
+
+#[test]
+fn indexed_by_variant() {
+    assert_eq!(COLOR.len(), <Player as DisjointGroup>::COUNT);
+    assert_eq!(COLOR[Player::INDEX], Color::srgb(0.0, 0.0, 1.0));
+    assert_eq!(COLOR[Ally::INDEX], Color::srgb(0.0, 1.0, 0.0));
+    assert_eq!(COLOR[Enemy::INDEX], Color::srgb(1.0, 0.0, 0.0));
+}
+
+#[test]
+fn any_meta_type() {
+    assert_eq!(
+        LABEL[Floor::INDEX],
+        Label {
+            name: "floor",
+            hostile: false
+        }
+    );
+    assert_eq!(
+        LABEL[Wall::INDEX].name,
+        <Wall as Disjoint>::NAME.to_lowercase()
+    );
+}
@@ -0,0 +1,67 @@
+//! Checks that `migrate_from_enum` replaces an old enum component with the matching "variant".
+
+use bevy::{
+    ecs::system::RunSystemOnce,
+    prelude::{Component, World},
+};
+use bevy_djqf::{disjoint, migrate_from_enum};
+
+#[derive(Component, Clone, Copy)]
+enum OldKind {
+    Player,
+    Enemy,
+    Wall,
+    Removed,
+}
+
+#[derive(Component, Debug, Default)]
+struct Player;
+
+#[derive(Component, Debug, Default)]
+struct Enemy;
+
+#[derive(Component, Debug, Default)]
+struct Wall;
+
+disjoint!(Player, Enemy, Wall);
+
+fn index(kind: &OldKind) -> usize {
+    *kind as usize
+}
+
+#[test]
+fn migrates_every_entity() {
+    let mut world = World::new();
+    let player = world.spawn(OldKind::Player).id();
+    // an existing "variant" is replaced
+    let enemy = world.spawn((OldKind::Enemy, Wall)).id();
+    let wall = world.spawn(OldKind::Wall).id();
+    let removed = world.spawn(OldKind::Removed).id();
+    let untouched = world.spawn(Player).id();
+
+    world.run_system_once(migrate_from_enum::<OldKind, Player>(index, true));
+
+    assert!(world.entity(player).contains::<Player>());
+    assert!(world.entity(enemy).contains::<Enemy>());
+    assert!(!world.entity(enemy).contains::<Wall>());
+    assert!(world.entity(wall).contains::<Wall>());
+    for entity in [player, enemy, wall] {
+        assert!(!world.entity(entity).contains::<OldKind>());
+    }
+
+    // out of range, so skipped
+    assert!(world.entity(removed).contains::<OldKind>());
+    assert!(!world.entity(removed).contains::<Player>());
+    assert!(world.entity(untouched).contains::<Player>());
+}
+
+#[test]
+fn keeps_old_component() {
+    let mut world = World::new();
+    let enemy = world.spawn(OldKind::Enemy).id();
+
+    world.run_system_once(migrate_from_enum::<OldKind, Player>(index, false));
+
+    assert!(world.entity(enemy).contains::<Enemy>());
+    assert!(world.entity(enemy).contains::<OldKind>());
+}
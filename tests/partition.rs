@@ -0,0 +1,133 @@
+//! Checks that the filters generated by `disjoint!` partition entities correctly, for groups of 2 to 8 (and 16) members,
+//! by spawning one entity for every combination of members.
+
+use bevy::{
+    ecs::query::QueryFilter,
+    prelude::{Component, Entity, World},
+};
+use bevy_djqf::{Disjoint, DisjointGroup};
+
+fn matching<F: QueryFilter>(world: &mut World) -> Vec<Entity> {
+    let mut entities = world
+        .query_filtered::<Entity, F>()
+        .iter(world)
+        .collect::<Vec<_>>();
+    entities.sort();
+    entities
+}
+
+fn expected(entities: &[(Entity, u32)], predicate: impl Fn(u32) -> bool) -> Vec<Entity> {
+    let mut entities = entities
+        .iter()
+        .filter(|(_, mask)| predicate(*mask))
+        .map(|(entity, _)| *entity)
+        .collect::<Vec<_>>();
+    entities.sort();
+    entities
+}
+
+fn check_member<V: Disjoint>(world: &mut World, entities: &[(Entity, u32)]) {
+    let bit = 1 << V::INDEX;
+    let only = matching::<V::Only>(world);
+    let other = matching::<V::Other>(world);
+    let any = matching::<V::Any>(world);
+    let none = matching::<V::None>(world);
+    let not = matching::<V::Not>(world);
+
+    assert_eq!(
+        only,
+        expected(entities, |mask| mask == bit),
+        "{} Only",
+        V::NAME
+    );
+    assert_eq!(
+        other,
+        expected(entities, |mask| mask != 0 && mask & bit == 0),
+        "{} Other",
+        V::NAME
+    );
+    assert_eq!(any, expected(entities, |mask| mask != 0), "{} Any", V::NAME);
+    assert_eq!(
+        none,
+        expected(entities, |mask| mask == 0),
+        "{} None",
+        V::NAME
+    );
+
+    assert_eq!(
+        not,
+        expected(entities, |mask| mask & bit == 0),
+        "{} Not",
+        V::NAME
+    );
+    // unlike `Other`, `Not` includes entities with no member
+    let mut other_or_none = [other.clone(), none.clone()].concat();
+    other_or_none.sort();
+    assert_eq!(not, other_or_none, "{} Not is Other or None", V::NAME);
+
+    // `Only`, `Other`, `None`, and having `V` along with other members, partition every entity
+    let with_others = expected(entities, |mask| mask & bit != 0 && mask != bit);
+    let mut partition = [only, other, none, with_others].concat();
+    partition.sort();
+    assert_eq!(
+        partition,
+        expected(entities, |_| true),
+        "{} partition",
+        V::NAME
+    );
+}
+
+macro_rules! check_group {
+    ($test:ident: $( $member:ident ),+) => {
+        #[test]
+        fn $test() {
+            $(
+                #[derive(Component)]
+                struct $member;
+            )+
+
+            bevy_djqf::disjoint!($( $member ),+);
+
+            type Group = <check_group!(@first $( $member ),+) as Disjoint>::Group;
+
+            // one entity for every combination of members, including none, paired with its combination as a bitmask
+            let mut world = World::new();
+            let entities = (0..1u32 << <Group as DisjointGroup>::COUNT)
+                .map(|mask| {
+                    let mut entity = world.spawn_empty();
+                    $(
+                        if mask & (1 << <$member as Disjoint>::INDEX) != 0 {
+                            entity.insert($member);
+                        }
+                    )+
+                    (entity.id(), mask)
+                })
+                .collect::<Vec<_>>();
+
+            $( check_member::<$member>(&mut world, &entities); )+
+
+            // the `Only` filters of every member, and `None`, match each entity with fewer than two members exactly once
+            let mut exactly_one_or_none = [
+                $( matching::<<$member as Disjoint>::Only>(&mut world), )+
+                matching::<<Group as Disjoint>::None>(&mut world),
+            ]
+            .concat();
+            exactly_one_or_none.sort();
+            assert_eq!(exactly_one_or_none, expected(&entities, |mask| mask.count_ones() < 2));
+        }
+    };
+
+    (@first $first:ident $(, $rest:ident )*) => {
+        $first
+    };
+}
+
+check_group!(group_of_2: A, B);
+check_group!(group_of_3: A, B, C);
+check_group!(group_of_4: A, B, C, D);
+check_group!(group_of_5: A, B, C, D, E);
+check_group!(group_of_6: A, B, C, D, E, F);
+check_group!(group_of_7: A, B, C, D, E, F, G);
+check_group!(group_of_8: A, B, C, D, E, F, G, H);
+// past 15 members, the filters are nested
+check_group!(group_of_16: A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P);
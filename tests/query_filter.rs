@@ -0,0 +1,105 @@
+//! Checks at compile time that every filter generated by `disjoint!` implements `QueryFilter`,
+//! for groups of several sizes and with the other forms of the macro.
+//! Nothing runs: this file failing to compile is the failure.
+
+// the markers are only used in types, never constructed
+#![allow(dead_code)]
+
+use bevy::{ecs::query::QueryFilter, prelude::Component};
+use bevy_djqf::{disjoint, Disjoint};
+
+fn assert_filter<F: QueryFilter>() {}
+
+macro_rules! assert_filters {
+    ($( $member:ty ),+) => {
+        const _: fn() = || {
+            $(
+                assert_filter::<<$member as Disjoint>::Only>();
+                assert_filter::<<$member as Disjoint>::Other>();
+                assert_filter::<<$member as Disjoint>::Any>();
+                assert_filter::<<$member as Disjoint>::None>();
+                assert_filter::<<$member as Disjoint>::Not>();
+            )+
+        };
+    };
+}
+
+macro_rules! check_group {
+    ($module:ident: $( $member:ident ),+) => {
+        mod $module {
+            use super::*;
+
+            $(
+                #[derive(Component)]
+                pub struct $member;
+            )+
+
+            disjoint!($( $member ),+);
+
+            assert_filters!($( $member ),+);
+        }
+    };
+}
+
+check_group!(group_of_2: A, B);
+check_group!(group_of_3: A, B, C);
+check_group!(group_of_5: A, B, C, D, E);
+check_group!(group_of_8: A, B, C, D, E, F, G, H);
+// past 15 members, the filters are nested
+check_group!(group_of_16: A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P);
+check_group!(group_of_17: A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P, Q);
+
+mod tagged {
+    use super::*;
+
+    #[derive(Component)]
+    pub struct Tag;
+
+    #[derive(Component)]
+    pub struct A;
+
+    #[derive(Component)]
+    pub struct B;
+
+    #[derive(Component)]
+    pub struct C;
+
+    disjoint!(in Tag; A, B, C);
+
+    assert_filters!(A, B, C);
+}
+
+mod generic {
+    use std::marker::PhantomData;
+
+    use super::*;
+
+    #[derive(Component)]
+    pub struct A<T: Send + Sync + 'static>(PhantomData<T>);
+
+    #[derive(Component)]
+    pub struct B<T: Send + Sync + 'static>(PhantomData<T>);
+
+    disjoint!(<T> A<T>, B<T> where T: Send + Sync + 'static);
+
+    assert_filters!(A<u8>, B<u8>);
+}
+
+#[cfg(feature = "derive")]
+mod derived {
+    use super::*;
+
+    #[derive(bevy_djqf::Disjoint)]
+    pub enum GameObject {
+        Player,
+        Enemy,
+        Wall,
+    }
+
+    assert_filters!(Player, Enemy, Wall);
+
+    const _: fn() = || {
+        assert_filter::<GameObjectAny>();
+        assert_filter::<GameObjectNone>();
+    };
+}
@@ -0,0 +1,48 @@
+//! Checks that a `QueryState` from `only_query_state` can be cached in a resource and reused across updates.
+
+use bevy::{
+    ecs::query::QueryState,
+    prelude::{App, Component, Entity, Mut, Name, Resource, Update, World},
+};
+use bevy_djqf::{disjoint, only_query_state, Disjoint};
+
+#[derive(Component, Debug, Default)]
+struct Player;
+
+#[derive(Component, Debug, Default)]
+struct Enemy;
+
+disjoint!(Player, Enemy);
+
+#[derive(Resource)]
+struct Players(QueryState<Entity, <Player as Disjoint>::Only>);
+
+#[derive(Resource, Default)]
+struct Counts(Vec<usize>);
+
+fn count_players(world: &mut World) {
+    world.resource_scope(|world, mut players: Mut<Players>| {
+        let count = players.0.iter(world).count();
+        world.resource_mut::<Counts>().0.push(count);
+    });
+}
+
+#[test]
+fn cached_across_updates() {
+    let mut app = App::new();
+    let state = only_query_state::<Player, Entity>(app.world_mut());
+    app.insert_resource(Players(state))
+        .init_resource::<Counts>()
+        .add_systems(Update, count_players);
+
+    app.world_mut().spawn(Player);
+    app.world_mut().spawn(Enemy);
+    app.update();
+
+    // new archetypes are picked up by the cached state
+    app.world_mut().spawn((Player, Name::new("second")));
+    app.world_mut().spawn((Player, Enemy));
+    app.update();
+
+    assert_eq!(app.world().resource::<Counts>().0, [1, 2]);
+}
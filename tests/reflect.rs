@@ -0,0 +1,31 @@
+//! Checks that with the `reflect` feature, `make_disjoint_markers!` derives `Reflect` for markers with derives,
+//! unless the derives already include it, so that the group can be registered.
+
+#[cfg(feature = "reflect")]
+mod markers {
+    use bevy::prelude::{App, AppTypeRegistry, Component, Reflect};
+    use bevy_djqf::{make_disjoint_markers, DisjointReflectAppExt};
+
+    macro_rules! type_template {
+        ($(#[$attr:meta])* $Name:ident) => {
+            $(#[$attr])*
+            struct $Name;
+        };
+    }
+
+    make_disjoint_markers!(type_template, derive(Component, Debug, Default) for Red, Green);
+
+    // `Reflect` isn't derived twice
+    make_disjoint_markers!(type_template, derive(Component, Reflect) for Circle, Square);
+
+    #[test]
+    fn derives_reflect() {
+        let mut app = App::new();
+        app.register_disjoint_group::<Red>()
+            .register_disjoint_group::<Circle>();
+
+        let registry = app.world().resource::<AppTypeRegistry>().read();
+        assert!(registry.contains(std::any::TypeId::of::<Green>()));
+        assert!(registry.contains(std::any::TypeId::of::<Square>()));
+    }
+}
@@ -0,0 +1,57 @@
+//! Checks the filters of a `DisjointRegistry` before and after members are registered.
+
+use bevy::{
+    ecs::query::QueryBuilder,
+    prelude::{App, Component, Entity, World},
+};
+use bevy_djqf::{DisjointRegistry, DisjointRegistryAppExt};
+
+struct GameObject;
+
+#[derive(Component)]
+struct Player;
+
+#[derive(Component)]
+struct Goblin;
+
+fn matching(world: &mut World, filter: impl Fn(&mut QueryBuilder<Entity>)) -> Vec<Entity> {
+    let mut builder = QueryBuilder::<Entity>::new(world);
+    filter(&mut builder);
+    let mut query = builder.build();
+    let mut entities = query.iter(world).collect::<Vec<_>>();
+    entities.sort();
+    entities
+}
+
+#[test]
+fn empty_registry_matches_nothing() {
+    let mut world = World::new();
+    world.spawn(Player);
+    world.spawn_empty();
+
+    let registry = DisjointRegistry::<GameObject>::default();
+    assert!(registry.is_empty());
+    assert!(matching(&mut world, |builder| registry.any(builder)).is_empty());
+    assert_eq!(
+        matching(&mut world, |builder| registry.none(builder)).len(),
+        2
+    );
+}
+
+#[test]
+fn any_member() {
+    let mut app = App::new();
+    app.register_disjoint_member::<GameObject, Player>()
+        .register_disjoint_member::<GameObject, Goblin>();
+
+    let world = app.world_mut();
+    let player = world.spawn(Player).id();
+    let goblin = world.spawn(Goblin).id();
+    world.spawn_empty();
+
+    let registry = world.resource::<DisjointRegistry<GameObject>>().clone();
+    assert_eq!(
+        matching(world, |builder| registry.any(builder)),
+        [player, goblin]
+    );
+}
@@ -0,0 +1,68 @@
+//! Checks that `spawn_variants_from_fn` gives each entity the "variant" at the index from the closure,
+//! so `|i| i % COUNT` spreads the entities evenly, and that an out of range index spawns nothing.
+
+use bevy::{
+    ecs::world::CommandQueue,
+    prelude::{Commands, Component, Entity, World},
+};
+use bevy_djqf::{
+    disjoint, variant_histogram, DisjointCommandsExt, DisjointGroup, OutOfRange, VariantOf,
+};
+
+#[derive(Component, Debug, Default)]
+struct Red;
+
+#[derive(Component, Debug, Default)]
+struct Green;
+
+#[derive(Component, Debug, Default)]
+struct Blue;
+
+disjoint!(Red, Green, Blue);
+
+fn spawn(
+    world: &mut World,
+    n: usize,
+    f: impl FnMut(usize) -> usize,
+) -> Result<Vec<Entity>, OutOfRange> {
+    let mut queue = CommandQueue::default();
+    let entities = Commands::new(&mut queue, world).spawn_variants_from_fn::<Red>(n, f);
+    queue.apply(world);
+    entities
+}
+
+#[test]
+fn round_robin() {
+    let mut world = World::new();
+    let entities = spawn(&mut world, 10, |i| i % <Red as DisjointGroup>::COUNT).unwrap();
+    assert_eq!(entities.len(), 10);
+
+    let mut query = world.query::<VariantOf<Red>>();
+    for (i, entity) in entities.into_iter().enumerate() {
+        assert_eq!(query.get(&world, entity).unwrap(), Some(i % 3));
+    }
+    assert_eq!(
+        variant_histogram::<Red>(&world),
+        [("Red", 4), ("Green", 3), ("Blue", 3)]
+    );
+}
+
+#[test]
+fn constant() {
+    let mut world = World::new();
+    spawn(&mut world, 5, |_| 2).unwrap();
+    assert_eq!(
+        variant_histogram::<Red>(&world),
+        [("Red", 0), ("Green", 0), ("Blue", 5)]
+    );
+}
+
+#[test]
+fn out_of_range() {
+    let mut world = World::new();
+    assert_eq!(
+        spawn(&mut world, 10, |i| i),
+        Err(OutOfRange { index: 3, count: 3 })
+    );
+    assert_eq!(world.entities().len(), 0);
+}
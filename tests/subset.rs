@@ -0,0 +1,77 @@
+//! Checks that `disjoint_subset!` matches entities with any of the provided "variants", including more than one of them,
+//! and excludes the other members of the group, which it takes from the group.
+
+use bevy::ecs::query::QueryFilter;
+use bevy::prelude::{Component, Entity, World};
+use bevy_djqf::{disjoint, disjoint_subset};
+
+fn matching<F: QueryFilter>(world: &mut World) -> Vec<Entity> {
+    let mut entities = world
+        .query_filtered::<Entity, F>()
+        .iter(world)
+        .collect::<Vec<_>>();
+    entities.sort();
+    entities
+}
+
+#[derive(Component)]
+struct A;
+
+#[derive(Component)]
+struct B;
+
+#[derive(Component)]
+struct C;
+
+#[derive(Component)]
+struct D;
+
+disjoint!(A, B, C, D);
+
+#[test]
+fn subset() {
+    let mut world = World::new();
+    let a = world.spawn(A).id();
+    let b = world.spawn(B).id();
+    let c = world.spawn(C).id();
+    world.spawn(D);
+    world.spawn_empty();
+    let ab = world.spawn((A, B)).id();
+    let ac = world.spawn((A, C)).id();
+    world.spawn((B, D));
+    let abc = world.spawn((A, B, C)).id();
+
+    assert_eq!(matching::<disjoint_subset!(A, B)>(&mut world), [a, b, ab]);
+    // the order of the "variants" doesn't matter
+    assert_eq!(matching::<disjoint_subset!(B, A)>(&mut world), [a, b, ab]);
+    assert_eq!(
+        matching::<disjoint_subset!(A, B, C)>(&mut world),
+        [a, b, c, ab, ac, abc]
+    );
+    assert_eq!(matching::<disjoint_subset!(C)>(&mut world), [c]);
+}
+
+#[derive(Component)]
+struct Tag;
+
+#[derive(Component)]
+struct X;
+
+#[derive(Component)]
+struct Y;
+
+#[derive(Component)]
+struct Z;
+
+disjoint!(in Tag; X, Y, Z);
+
+#[test]
+fn subset_requires_the_marker() {
+    let mut world = World::new();
+    let x = world.spawn((Tag, X)).id();
+    let xy = world.spawn((Tag, X, Y)).id();
+    world.spawn(X);
+    world.spawn((Tag, X, Z));
+
+    assert_eq!(matching::<disjoint_subset!(X, Y)>(&mut world), [x, xy]);
+}
@@ -0,0 +1,70 @@
+//! Checks that `swap_variants` exchanges the "variants" of two entities, including when one or both have none.
+
+use bevy::{
+    ecs::world::CommandQueue,
+    prelude::{Commands, Component, Entity, World},
+};
+use bevy_djqf::{disjoint, DisjointCommandsExt, VariantOf};
+
+#[derive(Component, Debug, Default)]
+struct A;
+
+#[derive(Component, Debug, Default)]
+struct B;
+
+#[derive(Component, Debug, Default)]
+struct C;
+
+disjoint!(A, B, C);
+
+fn swap(world: &mut World, x: Entity, y: Entity) {
+    let mut queue = CommandQueue::default();
+    Commands::new(&mut queue, world).swap_variants::<A>(x, y);
+    queue.apply(world);
+}
+
+fn variant(world: &mut World, entity: Entity) -> Option<usize> {
+    world.query::<VariantOf<A>>().get(world, entity).unwrap()
+}
+
+#[test]
+fn swap_a_and_b() {
+    let mut world = World::new();
+    let x = world.spawn(A).id();
+    let y = world.spawn(B).id();
+
+    swap(&mut world, x, y);
+    assert_eq!(variant(&mut world, x), Some(1));
+    assert_eq!(variant(&mut world, y), Some(0));
+
+    swap(&mut world, x, y);
+    assert_eq!(variant(&mut world, x), Some(0));
+    assert_eq!(variant(&mut world, y), Some(1));
+}
+
+#[test]
+fn swap_with_no_variant() {
+    let mut world = World::new();
+    let x = world.spawn(C).id();
+    let y = world.spawn_empty().id();
+    let z = world.spawn_empty().id();
+
+    swap(&mut world, x, y);
+    assert_eq!(variant(&mut world, x), None);
+    assert_eq!(variant(&mut world, y), Some(2));
+
+    swap(&mut world, x, z);
+    assert_eq!(variant(&mut world, x), None);
+    assert_eq!(variant(&mut world, z), None);
+}
+
+#[test]
+fn swap_with_despawned() {
+    let mut world = World::new();
+    let x = world.spawn(A).id();
+    let y = world.spawn(B).id();
+    world.despawn(y);
+
+    swap(&mut world, x, y);
+    assert_eq!(variant(&mut world, x), Some(0));
+}
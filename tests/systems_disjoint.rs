@@ -0,0 +1,45 @@
+//! Checks that `assert_systems_disjoint!` accepts "variants" of one group, and panics for "variants" that could
+//! be on the same entity, from the same or different groups.
+
+use bevy::prelude::Component;
+use bevy_djqf::{assert_systems_disjoint, check_systems_disjoint, disjoint};
+
+#[derive(Component)]
+struct Player;
+
+#[derive(Component)]
+struct Ally;
+
+#[derive(Component)]
+struct Enemy;
+
+disjoint!(Player, Ally, Enemy);
+
+#[derive(Component)]
+struct Flying;
+
+#[derive(Component)]
+struct Walking;
+
+disjoint!(Flying, Walking);
+
+assert_systems_disjoint!(teams: Player, Ally, Enemy);
+
+assert_systems_disjoint!(movement: Flying, Walking,);
+
+#[test]
+fn subset() {
+    assert_systems_disjoint!(Enemy, Player);
+}
+
+#[test]
+#[should_panic]
+fn same_variant() {
+    check_systems_disjoint::<Ally, Ally>();
+}
+
+#[test]
+#[should_panic]
+fn different_groups() {
+    assert_systems_disjoint!(Player, Ally, Walking);
+}
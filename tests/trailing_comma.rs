@@ -0,0 +1,36 @@
+//! Checks that `disjoint!` accepts a trailing comma, with and without explicit indices.
+
+use bevy::prelude::{Component, Entity, World};
+use bevy_djqf::{disjoint, Disjoint, DisjointGroup};
+
+#[derive(Component)]
+struct A;
+
+#[derive(Component)]
+struct B;
+
+disjoint!(A, B,);
+
+#[derive(Component)]
+struct X;
+
+#[derive(Component)]
+struct Y;
+
+disjoint!(X = 1, Y = 0,);
+
+#[test]
+fn trailing_comma() {
+    assert_eq!(<A as DisjointGroup>::COUNT, 2);
+    assert_eq!(<B as Disjoint>::INDEX, 1);
+    assert_eq!(<X as Disjoint>::INDEX, 1);
+    assert_eq!(<Y as Disjoint>::INDEX, 0);
+
+    let mut world = World::new();
+    let a = world.spawn(A).id();
+    world.spawn(B);
+    world.spawn((A, B));
+
+    let mut only = world.query_filtered::<Entity, <A as Disjoint>::Only>();
+    assert_eq!(only.iter(&world).collect::<Vec<_>>(), [a]);
+}
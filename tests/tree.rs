@@ -0,0 +1,97 @@
+//! Checks that `disjoint_tree!` makes both levels disjoint, and that a leaf implies its parent,
+//! whether it is set with `set_subvariant` or spawned directly.
+
+use bevy::ecs::query::QueryFilter;
+use bevy::prelude::{App, Commands, Component, Entity, Update, World};
+use bevy_djqf::{disjoint_tree, Disjoint, DisjointEntityCommandsExt, Subvariant};
+
+#[derive(Component, Debug, Default)]
+struct Player;
+
+#[derive(Component, Debug, Default)]
+struct Npc;
+
+disjoint_tree! {
+    Player { FriendlyPlayer, EnemyPlayer },
+    Npc {
+        /// An NPC on the side of the players.
+        FriendlyAi,
+        EnemyAi,
+    },
+}
+
+fn matching<F: QueryFilter>(world: &mut World) -> Vec<Entity> {
+    let mut entities = world
+        .query_filtered::<Entity, F>()
+        .iter(world)
+        .collect::<Vec<_>>();
+    entities.sort();
+    entities
+}
+
+#[test]
+fn leaves_imply_their_parent() {
+    let mut world = World::new();
+    let friend = world.spawn(FriendlyPlayer).id();
+    let enemy = world.spawn(EnemyPlayer).id();
+    let ally = world.spawn(FriendlyAi).id();
+    let foe = world.spawn(EnemyAi).id();
+    world.flush();
+
+    // the parent level
+    assert_eq!(
+        matching::<<Player as Disjoint>::Only>(&mut world),
+        [friend, enemy]
+    );
+    assert_eq!(matching::<<Npc as Disjoint>::Only>(&mut world), [ally, foe]);
+
+    // the leaf level
+    assert_eq!(
+        matching::<<FriendlyPlayer as Disjoint>::Only>(&mut world),
+        [friend]
+    );
+    assert_eq!(
+        matching::<<FriendlyPlayer as Disjoint>::Other>(&mut world),
+        [enemy, ally, foe]
+    );
+}
+
+#[test]
+fn a_new_leaf_replaces_the_parent() {
+    let mut world = World::new();
+    let entity = world.spawn(FriendlyPlayer).id();
+    world.flush();
+    assert!(world.entity(entity).contains::<Player>());
+
+    world
+        .entity_mut(entity)
+        .remove::<FriendlyPlayer>()
+        .insert(EnemyAi);
+    world.flush();
+    assert!(world.entity(entity).contains::<Npc>());
+    assert!(!world.entity(entity).contains::<Player>());
+}
+
+#[test]
+fn set_subvariant_sets_both_levels() {
+    let mut app = App::new();
+    let entity = app.world_mut().spawn(EnemyAi).id();
+    app.add_systems(Update, move |mut commands: Commands| {
+        commands.entity(entity).set_subvariant::<FriendlyPlayer>();
+    });
+    app.update();
+
+    let entity = app.world().entity(entity);
+    assert!(entity.contains::<FriendlyPlayer>());
+    assert!(entity.contains::<Player>());
+    assert!(!entity.contains::<EnemyAi>());
+    assert!(!entity.contains::<Npc>());
+}
+
+#[test]
+fn leaves_know_their_parent() {
+    assert_eq!(
+        <<EnemyAi as Subvariant>::Parent as Disjoint>::INDEX,
+        <Npc as Disjoint>::INDEX
+    );
+}
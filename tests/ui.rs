@@ -0,0 +1,13 @@
+//! Checks the compile errors of the macros for invalid input, and of the bounds for types that don't fit them.
+
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/no_types.rs");
+    t.compile_fail("tests/ui/one_type.rs");
+    t.compile_fail("tests/ui/one_type_trailing_comma.rs");
+    t.compile_fail("tests/ui/not_a_member.rs");
+    // without the proc macros, a repeated type is only reported as conflicting impls
+    #[cfg(feature = "derive")]
+    t.compile_fail("tests/ui/duplicate.rs");
+}
@@ -0,0 +1,11 @@
+use bevy::prelude::Component;
+
+#[derive(Component)]
+struct A;
+
+#[derive(Component)]
+struct B;
+
+bevy_djqf::disjoint!(A, B, A);
+
+fn main() {}
@@ -0,0 +1,3 @@
+bevy_djqf::disjoint!();
+
+fn main() {}
@@ -0,0 +1,25 @@
+use bevy::prelude::Component;
+use bevy_djqf::{disjoint, GroupMember};
+
+#[derive(Component)]
+struct A;
+
+#[derive(Component)]
+struct B;
+
+#[derive(Component)]
+struct X;
+
+#[derive(Component)]
+struct Y;
+
+disjoint!(A, B);
+disjoint!(X, Y);
+
+fn index_in_group<G, T: GroupMember<G>>() -> usize {
+    T::INDEX
+}
+
+fn main() {
+    index_in_group::<A, X>();
+}
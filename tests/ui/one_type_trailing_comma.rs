@@ -0,0 +1,8 @@
+use bevy::prelude::Component;
+
+#[derive(Component)]
+struct A;
+
+bevy_djqf::disjoint!(A,);
+
+fn main() {}
@@ -0,0 +1,59 @@
+//! Checks that the `OnVariantChanged` and `OnBecameNone` events of a frame are in the order of the entities,
+//! whatever order they changed in.
+
+use bevy::prelude::{App, Component, Entity, Events};
+use bevy_djqf::{
+    disjoint, DisjointEntityWorldMutExt, OnBecameNone, OnVariantChanged, VariantChangedPlugin,
+};
+
+#[derive(Component, Debug, Default)]
+struct A;
+
+#[derive(Component, Debug, Default)]
+struct B;
+
+disjoint!(A, B);
+
+#[test]
+fn sorted_by_entity() {
+    let mut app = App::new();
+    app.add_plugins(VariantChangedPlugin::<A>::default());
+    let entities = (0..64)
+        .map(|_| app.world_mut().spawn(A).id())
+        .collect::<Vec<_>>();
+    app.update();
+
+    // changed in reverse order, and every other one loses its "variant"
+    for (i, &entity) in entities.iter().enumerate().rev() {
+        let mut entity = app.world_mut().entity_mut(entity);
+        if i % 2 == 0 {
+            entity.set_variant::<B>();
+        } else {
+            entity.remove::<A>();
+        }
+    }
+    app.update();
+
+    let events = app.world().resource::<Events<OnVariantChanged<A>>>();
+    let changed = events
+        .get_reader()
+        .read(events)
+        .filter(|event| event.old.is_some())
+        .map(|event| event.entity)
+        .collect::<Vec<_>>();
+    assert_eq!(changed, entities);
+
+    let events = app.world().resource::<Events<OnBecameNone<A>>>();
+    let became_none = events
+        .get_reader()
+        .read(events)
+        .map(|event| event.entity)
+        .collect::<Vec<_>>();
+    let expected = entities
+        .iter()
+        .copied()
+        .skip(1)
+        .step_by(2)
+        .collect::<Vec<Entity>>();
+    assert_eq!(became_none, expected);
+}